@@ -0,0 +1,90 @@
+use std::{hint::black_box, thread};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use data_router::{
+    arc_linker::ArcLinker,
+    atomic_linker::AtomicLinker,
+    receive::{Receive, ReceiverResult, SharedReceive},
+};
+
+const THREADS: usize = 4;
+const EVENTS_PER_THREAD: u64 = 10_000;
+
+struct Config {
+    scale: u64,
+}
+
+impl Receive<u64> for Config {
+    type Output = u64;
+
+    fn send(&mut self, event: u64) -> ReceiverResult<u64, Self::Output> {
+        ReceiverResult::Continue(event * self.scale)
+    }
+}
+
+impl SharedReceive<u64> for Config {
+    type Output = u64;
+
+    fn send(&self, event: u64) -> ReceiverResult<u64, Self::Output> {
+        ReceiverResult::Continue(event * self.scale)
+    }
+}
+
+fn single_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_thread");
+
+    let arc_linker = ArcLinker::new(Config { scale: 3 });
+    let mut arc_linked = arc_linker.linked();
+    group.bench_function("arc_linked", |b| {
+        b.iter(|| Receive::send(&mut arc_linked, black_box(7)).unwrap_continue())
+    });
+
+    let atomic_linker = AtomicLinker::new(Config { scale: 3 });
+    let atomic_linked = atomic_linker.linked();
+    group.bench_function("atomic_linked", |b| {
+        b.iter(|| SharedReceive::send(&atomic_linked, black_box(7)).unwrap_continue())
+    });
+
+    group.finish();
+}
+
+fn contended(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended");
+
+    let arc_linker = ArcLinker::new(Config { scale: 3 });
+    group.bench_function("arc_linked", |b| {
+        b.iter(|| {
+            thread::scope(|scope| {
+                for _ in 0..THREADS {
+                    let mut linked = arc_linker.linked();
+                    scope.spawn(move || {
+                        for event in 0..EVENTS_PER_THREAD {
+                            black_box(Receive::send(&mut linked, event).unwrap_continue());
+                        }
+                    });
+                }
+            })
+        })
+    });
+
+    let atomic_linker = AtomicLinker::new(Config { scale: 3 });
+    group.bench_function("atomic_linked", |b| {
+        b.iter(|| {
+            thread::scope(|scope| {
+                for _ in 0..THREADS {
+                    let linked = atomic_linker.linked();
+                    scope.spawn(move || {
+                        for event in 0..EVENTS_PER_THREAD {
+                            black_box(SharedReceive::send(&linked, event).unwrap_continue());
+                        }
+                    });
+                }
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, single_thread, contended);
+criterion_main!(benches);
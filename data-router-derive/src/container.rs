@@ -0,0 +1,370 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    meta::ParseNestedMeta,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Fields, Generics, Ident, Path, Result, Token, Type, WherePredicate,
+};
+
+pub struct EventMapping {
+    pub event: Type,
+    pub output: Type,
+}
+
+impl Parse for EventMapping {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let event: Type = input.parse()?;
+        let output = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            input.parse()?
+        } else {
+            event.clone()
+        };
+        Ok(Self { event, output })
+    }
+}
+
+pub struct Group {
+    pub field: Ident,
+    pub trait_path: Path,
+    pub events: Vec<EventMapping>,
+}
+
+pub struct Container {
+    pub name: Ident,
+    pub generics: Generics,
+    pub receiver: Ident,
+    pub receiver_ty: Type,
+    pub groups: Vec<Group>,
+    // the user fields that are neither the receiver nor a group, filled with `Default` by `new`
+    pub extra: Vec<(Ident, Type)>,
+    pub pass: Vec<EventMapping>,
+    pub implements: Vec<Path>,
+}
+
+fn parse_events(meta: &ParseNestedMeta) -> Result<Vec<EventMapping>> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    Ok(
+        Punctuated::<EventMapping, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect(),
+    )
+}
+
+impl Container {
+    // `attr` is the helper attribute namespace, `group` the field key naming the trait of the group
+    pub fn parse(input: &DeriveInput, attr: &str, group: &str) -> Result<Self> {
+        let derive = input.ident.span();
+        let fields = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => {
+                    return Err(Error::new(
+                        derive,
+                        format!("`{attr}` can only be derived for structs with named fields"),
+                    ))
+                }
+            },
+            _ => {
+                return Err(Error::new(
+                    derive,
+                    format!("`{attr}` can only be derived for structs"),
+                ))
+            }
+        };
+
+        let mut pass = Vec::new();
+        let mut implements = Vec::new();
+        for attribute in input.attrs.iter().filter(|a| a.path().is_ident(attr)) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("pass") {
+                    pass.extend(parse_events(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("implements") && group == "intercept" {
+                    implements.push(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("unknown `{attr}` attribute")))
+                }
+            })?;
+        }
+
+        let mut receiver: Option<(Ident, Type)> = None;
+        let mut groups: Vec<Group> = Vec::new();
+        let mut unmarked: Vec<(Ident, Type)> = Vec::new();
+        for field in fields {
+            let ident = field.ident.clone().unwrap();
+            let mut is_receiver = false;
+            let mut trait_path: Option<Path> = None;
+            let mut events = None;
+
+            for attribute in field.attrs.iter().filter(|a| a.path().is_ident(attr)) {
+                attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("receiver") {
+                        is_receiver = true;
+                        Ok(())
+                    } else if meta.path.is_ident(group) {
+                        trait_path = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("events") {
+                        events = Some(parse_events(&meta)?);
+                        Ok(())
+                    } else {
+                        Err(meta.error(format!("unknown `{attr}` field attribute")))
+                    }
+                })?;
+
+                match (&trait_path, &events) {
+                    (Some(_), None) => {
+                        return Err(Error::new_spanned(
+                            attribute,
+                            format!("`{group}` requires the handled `events(..)`"),
+                        ))
+                    }
+                    (None, Some(_)) => {
+                        return Err(Error::new_spanned(
+                            attribute,
+                            format!("`events(..)` requires a `{group} = Trait`"),
+                        ))
+                    }
+                    (Some(_), Some(events)) if events.is_empty() => {
+                        return Err(Error::new_spanned(attribute, "`events(..)` is empty"))
+                    }
+                    _ => {}
+                }
+            }
+
+            let grouped = trait_path.is_some();
+            if let (Some(trait_path), Some(events)) = (trait_path, events) {
+                if let Some(other) = groups
+                    .iter()
+                    .find(|g| same_path(&g.trait_path, &trait_path))
+                {
+                    return Err(Error::new_spanned(
+                        trait_path,
+                        format!("this trait is already used by `{}`", other.field),
+                    ));
+                }
+                groups.push(Group {
+                    field: ident.clone(),
+                    trait_path,
+                    events,
+                });
+            }
+
+            if is_receiver {
+                if receiver.is_some() {
+                    return Err(Error::new_spanned(
+                        field,
+                        "only one field can be the receiver",
+                    ));
+                }
+                receiver = Some((ident, field.ty.clone()));
+            } else if !grouped {
+                unmarked.push((ident, field.ty.clone()));
+            }
+        }
+
+        let (receiver, receiver_ty) = match receiver {
+            Some(receiver) => receiver,
+            None => match fields
+                .iter()
+                .find(|field| field.ident.as_ref().is_some_and(|i| i == "receiver"))
+            {
+                Some(field) => (field.ident.clone().unwrap(), field.ty.clone()),
+                None => {
+                    return Err(Error::new(
+                        derive,
+                        format!(
+                            "missing a `receiver` field or a field marked `#[{attr}(receiver)]`"
+                        ),
+                    ))
+                }
+            },
+        };
+
+        for path in &implements {
+            if !groups.iter().any(|g| same_path(&g.trait_path, path)) {
+                return Err(Error::new_spanned(
+                    path,
+                    format!("no field is a `{group}` of this trait"),
+                ));
+            }
+        }
+
+        let extra = unmarked
+            .into_iter()
+            .filter(|(ident, _)| *ident != receiver)
+            .collect();
+
+        Ok(Self {
+            name: input.ident.clone(),
+            generics: input.generics.clone(),
+            receiver,
+            receiver_ty,
+            groups,
+            extra,
+            pass,
+            implements,
+        })
+    }
+
+    pub fn group(&self, path: &Path) -> &Group {
+        self.groups
+            .iter()
+            .find(|g| same_path(&g.trait_path, path))
+            .unwrap()
+    }
+
+    // the struct generics with `receiver: Receive<event, Output = output>` added to the where clause
+    pub fn receive_generics(&self, mappings: &[(&Type, &Type)]) -> Generics {
+        let mut generics = self.generics.clone();
+        let receiver_ty = &self.receiver_ty;
+        let predicates = &mut generics.make_where_clause().predicates;
+        for (event, output) in mappings {
+            let predicate: WherePredicate = syn::parse2(quote_spanned! {event.span()=>
+                #receiver_ty: ::data_router::receive::Receive<#event, Output = #output>
+            })
+            .unwrap();
+            predicates.push(predicate);
+        }
+        generics
+    }
+
+    // the struct generics with `ty: bound` added to the where clause for each of `types`, the bounds are higher ranked so
+    // that one on a concrete type that doesn't hold disables the impl instead of failing to compile
+    fn bounded_generics<'t>(
+        &self,
+        types: impl IntoIterator<Item = &'t Type>,
+        bound: TokenStream,
+    ) -> Generics {
+        let mut generics = self.generics.clone();
+        let predicates = &mut generics.make_where_clause().predicates;
+        for ty in types {
+            predicates.push(syn::parse2(quote! { for<'__bound> #ty: #bound }).unwrap());
+        }
+        generics
+    }
+
+    // the inherent api and the `Default`, `Debug` and `Display` impls that `multi_router!` and `multi_exposed!` also
+    // generate, `empty` creates an empty group field and `accessor` borrows one, `describe` is the `Debug` text
+    pub fn common_impls(
+        &self,
+        group_ty: impl Fn(&Path) -> TokenStream,
+        empty: TokenStream,
+        accessor: impl Fn(&Ident) -> TokenStream,
+        describe: impl Fn(&Ident) -> TokenStream,
+    ) -> TokenStream {
+        let name = &self.name;
+        let receiver = &self.receiver;
+        let receiver_ty = &self.receiver_ty;
+        let fields: Vec<_> = self.groups.iter().map(|group| &group.field).collect();
+        let group_tys = self.groups.iter().map(|group| group_ty(&group.trait_path));
+        let accessors = fields.iter().map(|field| accessor(field));
+        let descriptions = fields.iter().map(|field| describe(field));
+        let extra: Vec<_> = self.extra.iter().map(|(field, _)| field).collect();
+        let extra_tys = self.extra.iter().map(|(_, ty)| ty);
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let new_generics =
+            self.bounded_generics(extra_tys.clone(), quote!(::std::default::Default));
+        let (new_impl_generics, _, new_where_clause) = new_generics.split_for_impl();
+        let default_generics = self.bounded_generics(
+            extra_tys.chain(std::iter::once(receiver_ty)),
+            quote!(::std::default::Default),
+        );
+        let (default_impl_generics, _, default_where_clause) = default_generics.split_for_impl();
+        let debug_generics =
+            self.bounded_generics(std::iter::once(receiver_ty), quote!(::std::fmt::Debug));
+        let (debug_impl_generics, _, debug_where_clause) = debug_generics.split_for_impl();
+        let display_generics =
+            self.bounded_generics(std::iter::once(receiver_ty), quote!(::std::fmt::Display));
+        let (display_impl_generics, _, display_where_clause) = display_generics.split_for_impl();
+
+        quote! {
+            #[allow(unused)]
+            impl #new_impl_generics #name #ty_generics #new_where_clause {
+                pub fn new(receiver: #receiver_ty) -> Self {
+                    Self {
+                        #receiver: receiver,
+                        #(#fields: #empty,)*
+                        #(#extra: ::std::default::Default::default(),)*
+                    }
+                }
+            }
+
+            #[allow(unused)]
+            impl #impl_generics #name #ty_generics #where_clause {
+                pub fn get_receiver(&self) -> &#receiver_ty {
+                    &self.#receiver
+                }
+
+                pub fn get_receiver_mut(&mut self) -> &mut #receiver_ty {
+                    &mut self.#receiver
+                }
+
+                #(pub fn #fields(&self) -> #group_tys {
+                    #accessors
+                })*
+            }
+
+            impl #default_impl_generics ::std::default::Default for #name #ty_generics #default_where_clause {
+                fn default() -> Self {
+                    Self::new(::std::default::Default::default())
+                }
+            }
+
+            impl #debug_impl_generics ::std::fmt::Debug for #name #ty_generics #debug_where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    let groups: &[(&str, ::std::string::String)] = &[
+                        #((::std::stringify!(#fields), #descriptions),)*
+                    ];
+                    ::std::write!(f, "{{[")?;
+                    for (i, (field, description)) in groups.iter().enumerate() {
+                        if i > 0 {
+                            ::std::write!(f, ", ")?;
+                        }
+                        ::std::write!(f, "{}: {}", field, description)?;
+                    }
+                    ::std::write!(f, "], {:?}}}", self.#receiver)
+                }
+            }
+
+            impl #display_impl_generics ::std::fmt::Display for #name #ty_generics #display_where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    ::std::fmt::Display::fmt(&self.#receiver, f)
+                }
+            }
+        }
+    }
+
+    pub fn pass_impls(&self) -> TokenStream {
+        let name = &self.name;
+        let receiver = &self.receiver;
+        let receiver_ty = &self.receiver_ty;
+
+        self.pass
+            .iter()
+            .map(|EventMapping { event, output }| {
+                let generics = self.receive_generics(&[(event, output)]);
+                let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics ::data_router::receive::Receive<#event> for #name #ty_generics #where_clause {
+                        type Output = #output;
+
+                        fn send(&mut self, event: #event) -> ::data_router::receive::ReceiverResult<#event, Self::Output> {
+                            <#receiver_ty as ::data_router::receive::Receive<#event>>::send(&mut self.#receiver, event)
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    a.to_token_stream().to_string() == b.to_token_stream().to_string()
+}
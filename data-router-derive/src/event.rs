@@ -0,0 +1,227 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Ident, Result, Type, Variant};
+
+struct Receiver {
+    ty: Type,
+    output: Option<Type>,
+}
+
+struct EventVariant<'a> {
+    variant: &'a Variant,
+    handler: Option<Ident>,
+}
+
+impl EventVariant<'_> {
+    // the payload type sent to the receiver, `()` for unit variants and a tuple for several fields
+    fn payload_ty(&self) -> TokenStream {
+        let types: Vec<_> = self.variant.fields.iter().map(|field| &field.ty).collect();
+        match types.as_slice() {
+            [ty] => quote!(#ty),
+            types => quote!((#(#types),*)),
+        }
+    }
+
+    fn bindings(&self) -> Vec<Ident> {
+        (0..self.variant.fields.len())
+            .map(|i| format_ident!("field_{}", i))
+            .collect()
+    }
+
+    // the variant pattern with every field bound, doubling as its constructor
+    fn pattern(&self, name: &Ident) -> TokenStream {
+        let variant = &self.variant.ident;
+        let bindings = self.bindings();
+        match &self.variant.fields {
+            Fields::Unit => quote!(#name::#variant),
+            Fields::Unnamed(_) => quote!(#name::#variant(#(#bindings),*)),
+            Fields::Named(fields) => {
+                let idents = fields.named.iter().map(|field| &field.ident);
+                quote!(#name::#variant { #(#idents: #bindings),* })
+            }
+        }
+    }
+
+    fn payload(&self) -> TokenStream {
+        let bindings = self.bindings();
+        match bindings.as_slice() {
+            [binding] => quote!(#binding),
+            bindings => quote!((#(#bindings),*)),
+        }
+    }
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`Event` can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut receivers = Vec::new();
+    let mut viewers = Vec::new();
+    for attribute in input.attrs.iter().filter(|a| a.path().is_ident("event")) {
+        let mut receiver: Option<Receiver> = None;
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("receiver") {
+                receiver = Some(Receiver {
+                    ty: meta.value()?.parse()?,
+                    output: None,
+                });
+                Ok(())
+            } else if meta.path.is_ident("output") {
+                match receiver {
+                    Some(ref mut receiver) => {
+                        receiver.output = Some(meta.value()?.parse()?);
+                        Ok(())
+                    }
+                    None => Err(meta.error("`output` must follow a `receiver = Type`")),
+                }
+            } else if meta.path.is_ident("viewer") {
+                viewers.push(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown `event` attribute"))
+            }
+        })?;
+        receivers.extend(receiver);
+    }
+
+    if receivers.is_empty() && viewers.is_empty() {
+        return Err(Error::new(
+            input.ident.span(),
+            "missing `#[event(receiver = Type)]` or `#[event(viewer = Type)]`",
+        ));
+    }
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let mut handler = None;
+        for attribute in variant.attrs.iter().filter(|a| a.path().is_ident("event")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("handler") {
+                    handler = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `event` variant attribute"))
+                }
+            })?;
+        }
+        variants.push(EventVariant { variant, handler });
+    }
+
+    let receives = receivers
+        .iter()
+        .map(|receiver| receive_impl(input, &variants, receiver))
+        .collect::<Result<TokenStream>>()?;
+    let views = viewers
+        .iter()
+        .map(|viewer| view_impl(input, &variants, viewer))
+        .collect::<Result<TokenStream>>()?;
+
+    Ok(quote! {
+        #receives
+        #views
+    })
+}
+
+fn receive_impl(
+    input: &DeriveInput,
+    variants: &[EventVariant],
+    receiver: &Receiver,
+) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let receiver_ty = &receiver.ty;
+
+    let output = match (&receiver.output, variants.first()) {
+        (Some(output), _) => quote!(#output),
+        (None, Some(first)) if first.handler.is_none() => {
+            let payload_ty = first.payload_ty();
+            quote!(<#receiver_ty as ::data_router::receive::Receive<#payload_ty>>::Output)
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                receiver_ty,
+                "the output can't be inferred, add `output = Type` after the receiver",
+            ))
+        }
+    };
+
+    let arms = variants.iter().map(|event_variant| {
+        let pattern = event_variant.pattern(name);
+        let payload = event_variant.payload();
+        let payload_ty = event_variant.payload_ty();
+        let send = match &event_variant.handler {
+            Some(handler) => quote_spanned!(handler.span()=> <#receiver_ty>::#handler(self, #payload)),
+            None => quote_spanned! {event_variant.variant.span()=>
+                <#receiver_ty as ::data_router::receive::Receive<#payload_ty>>::send(self, #payload)
+            },
+        };
+
+        quote! {
+            #pattern => match #send {
+                ::data_router::receive::ReceiverResult::Continue(output) => ::data_router::receive::ReceiverResult::Continue(output),
+                ::data_router::receive::ReceiverResult::Stop => ::data_router::receive::ReceiverResult::Stop,
+                ::data_router::receive::ReceiverResult::Delete(#payload) => ::data_router::receive::ReceiverResult::Delete(#pattern),
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::data_router::receive::Receive<#name #ty_generics> for #receiver_ty #where_clause {
+            type Output = #output;
+
+            fn send(&mut self, event: #name #ty_generics) -> ::data_router::receive::ReceiverResult<#name #ty_generics, Self::Output> {
+                match event {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn view_impl(input: &DeriveInput, variants: &[EventVariant], viewer: &Type) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let arms = variants
+        .iter()
+        .map(|event_variant| {
+            let pattern = event_variant.pattern(name);
+            let view = match event_variant.variant.fields.len() {
+                0 => quote_spanned! {event_variant.variant.span()=>
+                    <#viewer as ::data_router::view::View<()>>::view(self, &())
+                },
+                1 => {
+                    let payload = event_variant.payload();
+                    let payload_ty = event_variant.payload_ty();
+                    quote_spanned! {event_variant.variant.span()=>
+                        <#viewer as ::data_router::view::View<#payload_ty>>::view(self, #payload)
+                    }
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        event_variant.variant,
+                        "viewers can only be derived for variants with at most one field",
+                    ))
+                }
+            };
+            Ok(quote!(#pattern => #view,))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::data_router::view::View<#name #ty_generics> for #viewer #where_clause {
+            fn view(&mut self, event: &#name #ty_generics) -> ::std::option::Option<::data_router::view::DeleteView> {
+                match event {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
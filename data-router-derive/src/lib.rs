@@ -0,0 +1,85 @@
+//! Derive macros for `data-router`, enabled through its `derive` feature
+//!
+//! - `#[derive(MultiRouter)]`: a struct with one intercept stack per group of events
+//!   - `#[multi_router(intercept = Trait, events(Event => Output, ..))]` on each intercept field, which should be an
+//!     `Option<Box<dyn Trait>>` of a trait created by `multi_router_intercept_trait!`
+//!   - `#[multi_router(receiver)]` marks the receiver, a field named `receiver` is used otherwise
+//!   - `#[multi_router(pass(Event => Output, ..))]` on the struct sends those events straight to the receiver
+//!   - `#[multi_router(implements = Trait)]` on the struct lets the router itself be used as an intercept of `Trait`
+//! - `#[derive(MultiExposed)]`: a struct with one set of viewers per group of events
+//!   - `#[multi_exposed(viewers = Trait, events(Event => Output, ..))]` on each viewer field, which should be a
+//!     `ReassignableCountedMap<usize, Box<dyn Trait>>` of a trait created by `multi_exposed_trait!`
+//!   - `#[multi_exposed(receiver)]` and `#[multi_exposed(pass(..))]` as above
+//!
+//! - `#[derive(Event)]`: an enum whose variants are dispatched to a receiver's per-payload `Receive` impls
+//!   - `#[event(receiver = Type, output = Output)]` on the enum implements `Receive<Enum>` for `Type`, the output
+//!     defaults to the one of the first variant's payload
+//!   - `#[event(viewer = Type)]` on the enum implements `View<Enum>` for `Type`
+//!   - `#[event(handler = method)]` on a variant calls `Type::method(&mut self, payload)` instead of `Receive`
+//!   - a payload is `()` for unit variants, the field for single field variants and a tuple of the fields otherwise,
+//!     a `Delete` of the payload is wrapped back into its variant
+//! - `#[receiver]`: on an inherent impl block, implements `Receive` or `View` once per annotated method
+//!   - `#[receive] fn on_event(&mut self, event: Event) -> ReceiverResult<Event, Output>` implements `Receive<Event>`,
+//!     any other return type is wrapped in `Continue` and becomes the output, no return type outputs `()`
+//!   - `#[view] fn on_event(&mut self, event: &Event) -> Option<DeleteView>` implements `View<Event>`, no return
+//!     type never deletes the viewer
+//!
+//! An event without `=> Output` outputs itself.
+//!
+//! Both derives also generate the api of `multi_router!` and `multi_exposed!`: `new(receiver)`, `get_receiver`,
+//! `get_receiver_mut`, an accessor named after each group field, and `Default`, `Debug` and `Display` impls when the
+//! receiver implements them, so the struct shouldn't derive those itself. `new` fills the extra fields with
+//! `Default::default()`.
+//!
+//! The struct may have any generic parameters, where clauses and extra fields, every generated impl keeps them and
+//! bounds the receiver field's type on the events it handles. Traits generic over a payload are declared with
+//! `multi_router_intercept_trait!(Trait<T> for Event<T>)` and `multi_exposed_trait!(Trait<T> for Event<T>)`.
+
+mod container;
+mod event;
+mod multi_exposed;
+mod multi_router;
+mod receiver;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemImpl};
+
+#[proc_macro_derive(MultiRouter, attributes(multi_router))]
+pub fn derive_multi_router(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    multi_router::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(MultiExposed, attributes(multi_exposed))]
+pub fn derive_multi_exposed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    multi_exposed::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(Event, attributes(event))]
+pub fn derive_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    event::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn receiver(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`#[receiver]` takes no arguments",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let input = parse_macro_input!(item as ItemImpl);
+    receiver::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
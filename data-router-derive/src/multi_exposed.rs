@@ -0,0 +1,124 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::container::{Container, EventMapping, Group};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let container = Container::parse(input, "multi_exposed", "viewers")?;
+
+    let receives: TokenStream = container
+        .groups
+        .iter()
+        .map(|group| receive_impls(&container, group))
+        .collect();
+    let multi_exposes: TokenStream = container
+        .groups
+        .iter()
+        .map(|group| multi_expose_impl(&container, group))
+        .collect();
+    let pass = container.pass_impls();
+    let common = container.common_impls(
+        |path| quote!(&::data_router::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn #path>>),
+        quote!(::data_router::counted_map::ReassignableCountedMap::new()),
+        |field| quote!(&self.#field),
+        |field| quote!(::std::string::ToString::to_string(&self.#field.len())),
+    );
+
+    let name = &container.name;
+    let (impl_generics, ty_generics, where_clause) = container.generics.split_for_impl();
+    let collects = container.groups.iter().map(|Group { field, trait_path, events }| {
+        let events = events.iter().map(|mapping| &mapping.event);
+        quote! {
+            let dead: ::std::vec::Vec<usize> = self.#field
+                .iter()
+                .filter(|(_, viewer)| !(true #(&& <dyn #trait_path as ::data_router::view::View<#events>>::is_alive(viewer.as_ref()))*))
+                .map(|(id, _)| *id)
+                .collect();
+
+            removed += dead.len();
+            for id in dead {
+                self.#field.remove(id);
+            }
+        }
+    });
+
+    Ok(quote! {
+        #receives
+        #pass
+        #multi_exposes
+        #common
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused)]
+            pub fn collect_garbage(&mut self) -> usize {
+                let mut removed = 0;
+                #({ #collects })*
+                removed
+            }
+        }
+    })
+}
+
+fn receive_impls(container: &Container, group: &Group) -> TokenStream {
+    let name = &container.name;
+    let receiver = &container.receiver;
+    let receiver_ty = &container.receiver_ty;
+    let Group {
+        field, trait_path, ..
+    } = group;
+
+    group
+        .events
+        .iter()
+        .map(|EventMapping { event, output }| {
+            let generics = container.receive_generics(&[(event, output)]);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            quote! {
+                impl #impl_generics ::data_router::receive::Receive<#event> for #name #ty_generics #where_clause {
+                    type Output = #output;
+
+                    fn send(&mut self, event: #event) -> ::data_router::receive::ReceiverResult<#event, Self::Output> {
+                        let mut deleted = ::std::vec::Vec::new();
+
+                        for (id, viewer) in self.#field.iter_mut() {
+                            if <dyn #trait_path as ::data_router::view::View<#event>>::view(viewer.as_mut(), &event).is_some() {
+                                deleted.push(*id);
+                            }
+                        }
+
+                        for id in deleted {
+                            self.#field.remove(id);
+                        }
+
+                        <#receiver_ty as ::data_router::receive::Receive<#event>>::send(&mut self.#receiver, event)
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn multi_expose_impl(container: &Container, group: &Group) -> TokenStream {
+    let name = &container.name;
+    let (impl_generics, ty_generics, where_clause) = container.generics.split_for_impl();
+    let Group {
+        field, trait_path, ..
+    } = group;
+
+    quote! {
+        impl #impl_generics ::data_router::multi_exposed::MultiExpose<dyn #trait_path> for #name #ty_generics #where_clause {
+            fn get_viewers(&self) -> &::data_router::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn #trait_path>> {
+                &self.#field
+            }
+
+            fn add_viewer(&mut self, other: ::std::boxed::Box<dyn #trait_path>) -> ::std::result::Result<usize, ::data_router::counted_map::HashMapFull> {
+                self.#field.push(other)
+            }
+
+            fn remove_viewer(&mut self, id: usize) -> ::std::option::Option<::std::boxed::Box<dyn #trait_path>> {
+                self.#field.remove(id)
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::container::{Container, EventMapping, Group};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let container = Container::parse(input, "multi_router", "intercept")?;
+
+    let receives: TokenStream = container
+        .groups
+        .iter()
+        .map(|group| receive_impls(&container, group))
+        .collect();
+    let multi_routes: TokenStream = container
+        .groups
+        .iter()
+        .map(|group| multi_route_impl(&container, group))
+        .collect();
+    let implements: TokenStream = container
+        .implements
+        .iter()
+        .map(|path| intercept_trait_impl(&container, container.group(path)))
+        .collect();
+    let pass = container.pass_impls();
+    let common = container.common_impls(
+        |path| quote!(::std::option::Option<&dyn #path>),
+        quote!(::std::option::Option::None),
+        |field| quote!(self.#field.as_ref().map(::std::boxed::Box::as_ref)),
+        |field| {
+            quote!(::std::string::ToString::to_string(match self.#field {
+                ::std::option::Option::Some(_) => "intercepted",
+                ::std::option::Option::None => "n/a",
+            }))
+        },
+    );
+
+    let name = &container.name;
+    let (impl_generics, ty_generics, where_clause) = container.generics.split_for_impl();
+    let traits = container.groups.iter().map(|group| &group.trait_path);
+
+    Ok(quote! {
+        #receives
+        #pass
+        #multi_routes
+        #implements
+        #common
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused)]
+            pub fn collect_garbage(&mut self) -> usize {
+                0 #(+ <Self as ::data_router::multi_router::MultiRoute<dyn #traits>>::collect_garbage(self))*
+            }
+        }
+    })
+}
+
+fn receive_impls(container: &Container, group: &Group) -> TokenStream {
+    let name = &container.name;
+    let receiver = &container.receiver;
+    let receiver_ty = &container.receiver_ty;
+    let Group {
+        field, trait_path, ..
+    } = group;
+
+    group
+        .events
+        .iter()
+        .map(|EventMapping { event, output }| {
+            let generics = container.receive_generics(&[(event, output)]);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            quote! {
+                impl #impl_generics ::data_router::receive::Receive<#event> for #name #ty_generics #where_clause {
+                    type Output = #output;
+
+                    fn send(&mut self, event: #event) -> ::data_router::receive::ReceiverResult<#event, Self::Output> {
+                        let event = if let ::std::option::Option::Some(ref mut intercept) = self.#field {
+                            match ::data_router::receive::Receive::<#event>::send(intercept.as_mut(), event) {
+                                ::data_router::receive::ReceiverResult::Continue(event) => event,
+                                ::data_router::receive::ReceiverResult::Stop => return ::data_router::receive::ReceiverResult::Stop,
+                                ::data_router::receive::ReceiverResult::Delete(event) => {
+                                    <Self as ::data_router::multi_router::MultiRoute<dyn #trait_path>>::delete_top_intercept(self).unwrap();
+                                    event
+                                }
+                            }
+                        } else {
+                            event
+                        };
+
+                        <#receiver_ty as ::data_router::receive::Receive<#event>>::send(&mut self.#receiver, event)
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn multi_route_impl(container: &Container, group: &Group) -> TokenStream {
+    let name = &container.name;
+    let (impl_generics, ty_generics, where_clause) = container.generics.split_for_impl();
+    let Group {
+        field, trait_path, ..
+    } = group;
+
+    quote! {
+        impl #impl_generics ::data_router::multi_router::MultiRoute<dyn #trait_path> for #name #ty_generics #where_clause {
+            fn take_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn #trait_path>> {
+                self.#field.take()
+            }
+
+            fn delete_top_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn #trait_path>> {
+                let mut old_intercept = self.#field.take();
+                if let ::std::option::Option::Some(ref mut intercept) = old_intercept {
+                    self.#field = <dyn #trait_path as #trait_path>::take_intercept(intercept.as_mut());
+                }
+                old_intercept
+            }
+
+            fn intercept(&mut self, intercept: ::std::boxed::Box<dyn #trait_path>) {
+                match self.#field {
+                    ::std::option::Option::Some(ref mut child) => <dyn #trait_path as #trait_path>::intercept(child.as_mut(), intercept),
+                    ::std::option::Option::None => self.#field = ::std::option::Option::Some(intercept),
+                }
+            }
+
+            fn collect_garbage(&mut self) -> usize {
+                let mut removed = 0;
+                while self.#field.as_ref().is_some_and(|intercept| !<dyn #trait_path as #trait_path>::is_alive(intercept.as_ref())) {
+                    <Self as ::data_router::multi_router::MultiRoute<dyn #trait_path>>::delete_top_intercept(self);
+                    removed += 1;
+                }
+
+                match self.#field {
+                    ::std::option::Option::Some(ref mut intercept) => removed + <dyn #trait_path as #trait_path>::collect_garbage(intercept.as_mut()),
+                    ::std::option::Option::None => removed,
+                }
+            }
+        }
+    }
+}
+
+// replaces `impl_multi_router_intercept_trait!`, letting the router itself be used as an intercept
+fn intercept_trait_impl(container: &Container, group: &Group) -> TokenStream {
+    let name = &container.name;
+    let receiver = &container.receiver;
+    let receiver_ty = &container.receiver_ty;
+    let trait_path = &group.trait_path;
+
+    let events: Vec<_> = group.events.iter().map(|mapping| &mapping.event).collect();
+    let mappings: Vec<_> = events.iter().map(|event| (*event, *event)).collect();
+    let generics = container.receive_generics(&mappings);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            fn take_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn #trait_path>> {
+                <Self as ::data_router::multi_router::MultiRoute<dyn #trait_path>>::take_intercept(self)
+            }
+
+            fn intercept(&mut self, intercept: ::std::boxed::Box<dyn #trait_path>) {
+                <Self as ::data_router::multi_router::MultiRoute<dyn #trait_path>>::intercept(self, intercept)
+            }
+
+            fn is_alive(&self) -> bool {
+                true #(&& <#receiver_ty as ::data_router::receive::Receive<#events>>::is_alive(&self.#receiver))*
+            }
+
+            fn collect_garbage(&mut self) -> usize {
+                <Self as ::data_router::multi_router::MultiRoute<dyn #trait_path>>::collect_garbage(self)
+            }
+        }
+    }
+}
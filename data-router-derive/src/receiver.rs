@@ -0,0 +1,195 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    spanned::Spanned, Error, FnArg, GenericArgument, ImplItem, ImplItemFn, ItemImpl, PathArguments,
+    Result, ReturnType, Type,
+};
+
+enum Kind {
+    Receive,
+    View,
+}
+
+pub fn expand(mut input: ItemImpl) -> Result<TokenStream> {
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "`#[receiver]` goes on an inherent impl block",
+        ));
+    }
+
+    let mut impls = Vec::new();
+    for item in &mut input.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+
+        let mut kind = None;
+        let mut error = None;
+        method.attrs.retain(|attribute| {
+            let found = if attribute.path().is_ident("receive") {
+                Kind::Receive
+            } else if attribute.path().is_ident("view") {
+                Kind::View
+            } else {
+                return true;
+            };
+            if kind.replace(found).is_some() {
+                error = Some(Error::new_spanned(
+                    attribute,
+                    "a method can only be one of `#[receive]` or `#[view]`",
+                ));
+            }
+            false
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        match kind {
+            Some(Kind::Receive) => impls.push(receive_impl(&input.self_ty, method)?),
+            Some(Kind::View) => impls.push(view_impl(&input.self_ty, method)?),
+            None => {}
+        }
+    }
+
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let self_ty = &input.self_ty;
+    let impls = impls.into_iter().map(|(trait_path, body)| {
+        quote! {
+            impl #impl_generics #trait_path for #self_ty #where_clause {
+                #body
+            }
+        }
+    });
+
+    Ok(quote! {
+        #input
+        #(#impls)*
+    })
+}
+
+// the type of the single argument after `&mut self`
+fn event_ty(method: &ImplItemFn) -> Result<&Type> {
+    let signature = &method.sig;
+    if !signature.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &signature.generics,
+            "receiver methods can't be generic",
+        ));
+    }
+
+    let mut inputs = signature.inputs.iter();
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some() && receiver.mutability.is_some() => {}
+        _ => {
+            return Err(Error::new_spanned(
+                signature,
+                "receiver methods take `&mut self`",
+            ))
+        }
+    }
+
+    match (inputs.next(), inputs.next()) {
+        (Some(FnArg::Typed(event)), None) => Ok(&event.ty),
+        _ => Err(Error::new_spanned(
+            &signature.inputs,
+            "receiver methods take a single event after `&mut self`",
+        )),
+    }
+}
+
+// the generic arguments of the return type if it is named `name`
+fn generic_args<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+fn receive_impl(self_ty: &Type, method: &ImplItemFn) -> Result<(TokenStream, TokenStream)> {
+    let event = event_ty(method)?;
+    let name = &method.sig.ident;
+    let span = method.sig.span();
+
+    let (output, body) = match &method.sig.output {
+        ReturnType::Default => (
+            quote!(()),
+            quote! {
+                <#self_ty>::#name(self, event);
+                ::data_router::receive::ReceiverResult::Continue(())
+            },
+        ),
+        ReturnType::Type(_, ty) => match generic_args(ty, "ReceiverResult").as_deref() {
+            Some([_, output]) => (quote!(#output), quote!(<#self_ty>::#name(self, event))),
+            Some(_) => {
+                return Err(Error::new_spanned(
+                    ty,
+                    "expected `ReceiverResult<Event, Output>`",
+                ))
+            }
+            None => (
+                quote!(#ty),
+                quote!(::data_router::receive::ReceiverResult::Continue(<#self_ty>::#name(self, event))),
+            ),
+        },
+    };
+
+    Ok((
+        quote_spanned!(span=> ::data_router::receive::Receive<#event>),
+        quote_spanned! {span=>
+            type Output = #output;
+
+            fn send(&mut self, event: #event) -> ::data_router::receive::ReceiverResult<#event, Self::Output> {
+                #body
+            }
+        },
+    ))
+}
+
+fn view_impl(self_ty: &Type, method: &ImplItemFn) -> Result<(TokenStream, TokenStream)> {
+    let event = match event_ty(method)? {
+        Type::Reference(reference) if reference.mutability.is_none() => &reference.elem,
+        ty => {
+            return Err(Error::new_spanned(
+                ty,
+                "viewer methods take the event by shared reference",
+            ))
+        }
+    };
+    let name = &method.sig.ident;
+    let span = method.sig.span();
+
+    let body = match &method.sig.output {
+        ReturnType::Default => quote! {
+            <#self_ty>::#name(self, event);
+            ::std::option::Option::None
+        },
+        ReturnType::Type(..) => quote!(<#self_ty>::#name(self, event)),
+    };
+
+    Ok((
+        quote_spanned!(span=> ::data_router::view::View<#event>),
+        quote_spanned! {span=>
+            fn view(&mut self, event: &#event) -> ::std::option::Option<::data_router::view::DeleteView> {
+                #body
+            }
+        },
+    ))
+}
@@ -0,0 +1,205 @@
+use data_router::{
+    counted_map::ReassignableCountedMap,
+    multi_exposed::{multi_exposed_trait, MultiExpose},
+    multi_router::{multi_router_intercept_trait, MultiRoute},
+    rc_linker::RcLinker,
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+use data_router_derive::{MultiExposed, MultiRouter};
+
+multi_router_intercept_trait!(LifeIntercept for i32 | bool);
+multi_exposed_trait!(LifeView for i32 | bool);
+
+#[derive(MultiRouter)]
+#[multi_router(pass(String => String), implements = LifeIntercept)]
+struct LifeRouter<R> {
+    #[multi_router(intercept = LifeIntercept, events(i32, bool))]
+    life: Option<Box<dyn LifeIntercept>>,
+    receiver: R,
+}
+
+#[derive(MultiRouter)]
+struct PlayerRouter {
+    #[multi_router(intercept = LifeIntercept, events(i32 => (), bool => ()))]
+    life: Option<Box<dyn LifeIntercept>>,
+    #[multi_router(receiver)]
+    player: Player,
+    name: &'static str,
+}
+
+#[derive(MultiExposed)]
+#[multi_exposed(pass(String => String))]
+struct PlayerExposed {
+    #[multi_exposed(viewers = LifeView, events(i32 => (), bool => ()))]
+    watchers: ReassignableCountedMap<usize, Box<dyn LifeView>>,
+    receiver: Player,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Player {
+    Alive { health: i32 },
+    Dead,
+}
+
+impl Receive<i32> for Player {
+    type Output = ();
+
+    fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+        if let Self::Alive { health } = self {
+            *health += event;
+        }
+        ReceiverResult::Continue(())
+    }
+}
+
+impl Receive<bool> for Player {
+    type Output = ();
+
+    fn send(&mut self, event: bool) -> ReceiverResult<bool, Self::Output> {
+        if !event {
+            *self = Self::Dead;
+        }
+        ReceiverResult::Continue(())
+    }
+}
+
+impl Receive<String> for Player {
+    type Output = String;
+
+    fn send(&mut self, event: String) -> ReceiverResult<String, Self::Output> {
+        ReceiverResult::Continue(format!("received event: {}", event))
+    }
+}
+
+#[derive(Default)]
+struct Shielded {
+    shielded: bool,
+}
+
+impl Receive<i32> for Shielded {
+    type Output = i32;
+
+    fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+        if self.shielded {
+            ReceiverResult::Stop
+        } else {
+            ReceiverResult::Continue(event)
+        }
+    }
+}
+
+impl Receive<bool> for Shielded {
+    type Output = bool;
+
+    fn send(&mut self, event: bool) -> ReceiverResult<bool, Self::Output> {
+        if event {
+            self.shielded = true;
+        }
+        ReceiverResult::Continue(event)
+    }
+}
+
+#[derive(Default)]
+struct Counter(usize);
+
+impl<E> View<E> for Counter {
+    fn view(&mut self, _: &E) -> Option<DeleteView> {
+        self.0 += 1;
+        None
+    }
+}
+
+#[test]
+fn derived_multi_router() {
+    let mut router = PlayerRouter {
+        life: None,
+        player: Player::Alive { health: 10 },
+        name: "player",
+    };
+
+    router.send(-2);
+    assert_eq!(router.player, Player::Alive { health: 8 });
+
+    let shield = RcLinker::new(Shielded::default());
+    router.intercept(Box::new(LifeRouter {
+        life: None,
+        receiver: shield.linked(),
+    }) as Box<dyn LifeIntercept>);
+
+    router.send(true);
+    router.send(-5);
+    assert_eq!(router.player, Player::Alive { health: 8 });
+    assert_eq!(router.name, "player");
+
+    assert_eq!(router.collect_garbage(), 0);
+    drop(shield);
+    assert_eq!(router.collect_garbage(), 1);
+
+    router.send(false);
+    assert_eq!(router.player, Player::Dead);
+}
+
+#[test]
+fn derived_pass_through() {
+    let mut router = LifeRouter {
+        life: None,
+        receiver: Shielded::default(),
+    };
+    assert_eq!(router.send(3).unwrap_continue(), 3);
+
+    let mut exposed = PlayerExposed {
+        watchers: ReassignableCountedMap::new(),
+        receiver: Player::Dead,
+    };
+    assert_eq!(
+        exposed.send("hello".to_string()).unwrap_continue(),
+        "received event: hello"
+    );
+}
+
+#[test]
+fn derived_multi_exposed() {
+    let mut exposed = PlayerExposed {
+        watchers: ReassignableCountedMap::new(),
+        receiver: Player::Alive { health: 1 },
+    };
+
+    let counter = RcLinker::new(Counter::default());
+    MultiExpose::<dyn LifeView>::add_viewer(&mut exposed, Box::new(counter.linked())).unwrap();
+
+    exposed.send(1);
+    exposed.send(false);
+    assert_eq!(counter.borrow().as_ref().unwrap().0, 2);
+    assert_eq!(exposed.receiver, Player::Dead);
+
+    drop(counter);
+    assert_eq!(exposed.collect_garbage(), 1);
+    assert!(exposed.watchers.is_empty());
+}
+
+#[test]
+fn derived_inherent_api() {
+    let mut router = PlayerRouter::new(Player::Alive { health: 3 });
+    assert!(router.life().is_none());
+    assert_eq!(router.name, "");
+    assert_eq!(
+        format!("{:?}", router),
+        "{[life: n/a], Alive { health: 3 }}"
+    );
+
+    router.get_receiver_mut().send(false);
+    assert_eq!(*router.get_receiver(), Player::Dead);
+
+    let mut life = LifeRouter::<Shielded>::default();
+    MultiRoute::<dyn LifeIntercept>::intercept(
+        &mut life,
+        Box::new(LifeRouter::new(Shielded::default())),
+    );
+    assert!(life.life().is_some());
+    assert!(!life.get_receiver().shielded);
+
+    let exposed = PlayerExposed::new(Player::Dead);
+    assert!(exposed.watchers().is_empty());
+    assert_eq!(format!("{:?}", exposed), "{[watchers: 0], Dead}");
+}
@@ -0,0 +1,165 @@
+use data_router::{
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+use data_router_derive::Event;
+
+#[derive(Debug, PartialEq, Event)]
+#[event(receiver = Player)]
+#[event(receiver = Shield, output = GameEvent)]
+#[event(viewer = Log)]
+enum GameEvent {
+    Damage(i32),
+    Rename {
+        name: String,
+    },
+    #[event(handler = on_revive)]
+    Revive,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Player {
+    health: i32,
+    name: String,
+}
+
+impl Player {
+    fn on_revive(&mut self, _: ()) -> ReceiverResult<(), ()> {
+        if self.health > 0 {
+            return ReceiverResult::Delete(());
+        }
+        self.health = 10;
+        ReceiverResult::Continue(())
+    }
+}
+
+impl Receive<i32> for Player {
+    type Output = ();
+
+    fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+        self.health -= event;
+        ReceiverResult::Continue(())
+    }
+}
+
+impl Receive<String> for Player {
+    type Output = ();
+
+    fn send(&mut self, event: String) -> ReceiverResult<String, Self::Output> {
+        if event.is_empty() {
+            return ReceiverResult::Delete(event);
+        }
+        self.name = event;
+        ReceiverResult::Continue(())
+    }
+}
+
+struct Shield;
+
+impl Shield {
+    fn on_revive(&mut self, _: ()) -> ReceiverResult<(), GameEvent> {
+        ReceiverResult::Stop
+    }
+}
+
+impl Receive<i32> for Shield {
+    type Output = GameEvent;
+
+    fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+        ReceiverResult::Continue(GameEvent::Damage(event / 2))
+    }
+}
+
+impl Receive<String> for Shield {
+    type Output = GameEvent;
+
+    fn send(&mut self, event: String) -> ReceiverResult<String, Self::Output> {
+        ReceiverResult::Continue(GameEvent::Rename { name: event })
+    }
+}
+
+#[derive(Default)]
+struct Log(Vec<String>);
+
+impl View<i32> for Log {
+    fn view(&mut self, event: &i32) -> Option<DeleteView> {
+        self.0.push(format!("damage {}", event));
+        None
+    }
+}
+
+impl View<String> for Log {
+    fn view(&mut self, event: &String) -> Option<DeleteView> {
+        self.0.push(format!("rename {}", event));
+        None
+    }
+}
+
+impl View<()> for Log {
+    fn view(&mut self, _: &()) -> Option<DeleteView> {
+        Some(DeleteView)
+    }
+}
+
+#[test]
+fn derived_event_receiver() {
+    let mut player = Player {
+        health: 5,
+        ..Default::default()
+    };
+
+    player.send(GameEvent::Damage(5)).unwrap_continue();
+    assert_eq!(player.health, 0);
+
+    player.send(GameEvent::Revive).unwrap_continue();
+    assert_eq!(player.health, 10);
+
+    player
+        .send(GameEvent::Rename {
+            name: "hero".to_string(),
+        })
+        .unwrap_continue();
+    assert_eq!(player.name, "hero");
+}
+
+#[test]
+fn derived_event_delete_rewraps() {
+    let mut player = Player {
+        health: 3,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        player.send(GameEvent::Revive),
+        ReceiverResult::Delete(GameEvent::Revive)
+    );
+    assert_eq!(
+        player.send(GameEvent::Rename {
+            name: String::new()
+        }),
+        ReceiverResult::Delete(GameEvent::Rename {
+            name: String::new()
+        })
+    );
+    assert_eq!(player.health, 3);
+}
+
+#[test]
+fn derived_event_output_and_view() {
+    let mut shield = Shield;
+    assert_eq!(
+        shield.send(GameEvent::Damage(8)).unwrap_continue(),
+        GameEvent::Damage(4)
+    );
+    assert_eq!(shield.send(GameEvent::Revive), ReceiverResult::Stop);
+
+    let mut log = Log::default();
+    assert!(log.view(&GameEvent::Damage(2)).is_none());
+    assert!(log
+        .view(&GameEvent::Rename {
+            name: "hero".to_string()
+        })
+        .is_none());
+    assert!(log.view(&GameEvent::Revive).is_some());
+    assert_eq!(log.0, ["damage 2", "rename hero"]);
+}
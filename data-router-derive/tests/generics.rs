@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+
+use data_router::{
+    counted_map::ReassignableCountedMap,
+    multi_exposed::{multi_exposed_trait, MultiExpose},
+    multi_router::{multi_router_intercept_trait, MultiRoute},
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+use data_router_derive::{MultiExposed, MultiRouter};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Payload<T>(T);
+
+multi_router_intercept_trait!(PayloadIntercept<T> for Payload<T> | u8);
+multi_exposed_trait!(PayloadView<T> for Payload<T>);
+
+#[derive(MultiRouter)]
+#[multi_router(pass(&'static str), implements = PayloadIntercept<T>)]
+struct PayloadRouter<'a, T: Clone + 'static, R>
+where
+    R: Debug,
+{
+    #[multi_router(intercept = PayloadIntercept<T>, events(Payload<T>, u8))]
+    payloads: Option<Box<dyn PayloadIntercept<T>>>,
+    receiver: R,
+    label: &'a str,
+    seen: usize,
+}
+
+#[derive(MultiExposed)]
+struct PayloadExposed<T: 'static, const N: usize> {
+    #[multi_exposed(viewers = PayloadView<T>, events(Payload<T> => [T; N]))]
+    viewers: ReassignableCountedMap<usize, Box<dyn PayloadView<T>>>,
+    #[multi_exposed(receiver)]
+    repeater: Repeat<N>,
+}
+
+#[derive(Debug, Default)]
+struct Collect<T>(Vec<T>);
+
+impl<T: Clone> Receive<Payload<T>> for Collect<T> {
+    type Output = Payload<T>;
+
+    fn send(&mut self, event: Payload<T>) -> ReceiverResult<Payload<T>, Self::Output> {
+        self.0.push(event.0.clone());
+        ReceiverResult::Continue(event)
+    }
+}
+
+impl<T> Receive<u8> for Collect<T> {
+    type Output = u8;
+
+    fn send(&mut self, event: u8) -> ReceiverResult<u8, Self::Output> {
+        ReceiverResult::Continue(event + 1)
+    }
+}
+
+impl<T> Receive<&'static str> for Collect<T> {
+    type Output = &'static str;
+
+    fn send(&mut self, event: &'static str) -> ReceiverResult<&'static str, Self::Output> {
+        ReceiverResult::Continue(event)
+    }
+}
+
+struct Double;
+
+impl Receive<Payload<i64>> for Double {
+    type Output = Payload<i64>;
+
+    fn send(&mut self, event: Payload<i64>) -> ReceiverResult<Payload<i64>, Self::Output> {
+        ReceiverResult::Continue(Payload(event.0 * 2))
+    }
+}
+
+impl Receive<u8> for Double {
+    type Output = u8;
+
+    fn send(&mut self, event: u8) -> ReceiverResult<u8, Self::Output> {
+        ReceiverResult::Continue(event * 2)
+    }
+}
+
+struct Repeat<const N: usize>;
+
+impl<T: Clone + Debug, const N: usize> Receive<Payload<T>> for Repeat<N> {
+    type Output = [T; N];
+
+    fn send(&mut self, event: Payload<T>) -> ReceiverResult<Payload<T>, Self::Output> {
+        ReceiverResult::Continue(std::array::from_fn(|_| event.0.clone()))
+    }
+}
+
+struct Count(usize);
+
+impl<T> View<Payload<T>> for Count {
+    fn view(&mut self, _: &Payload<T>) -> Option<DeleteView> {
+        self.0 += 1;
+        None
+    }
+}
+
+#[test]
+fn generic_multi_router() {
+    let label = String::from("borrowed");
+    let mut router = PayloadRouter {
+        payloads: None,
+        receiver: Collect::default(),
+        label: &label,
+        seen: 0,
+    };
+
+    MultiRoute::<dyn PayloadIntercept<i64>>::intercept(
+        &mut router,
+        Box::new(PayloadRouter {
+            payloads: None,
+            receiver: Debugged(Double),
+            label: "static",
+            seen: 0,
+        }),
+    );
+
+    assert_eq!(router.send(Payload(3)).unwrap_continue(), Payload(6));
+    assert_eq!(router.send(4u8).unwrap_continue(), 9);
+    assert_eq!(router.send("pass").unwrap_continue(), "pass");
+    assert_eq!(router.receiver.0, vec![6]);
+    assert_eq!(router.label, "borrowed");
+    assert_eq!(router.seen, 0);
+    assert_eq!(router.collect_garbage(), 0);
+
+    let mut defaulted = PayloadRouter::<i64, _>::new(Collect::default());
+    assert_eq!(defaulted.label, "");
+    assert_eq!(defaulted.send(Payload(1)).unwrap_continue(), Payload(1));
+    assert_eq!(defaulted.get_receiver().0, vec![1]);
+    assert!(defaulted.payloads().is_none());
+}
+
+#[test]
+fn generic_multi_exposed() {
+    let mut exposed = PayloadExposed::<char, 3> {
+        viewers: ReassignableCountedMap::new(),
+        repeater: Repeat,
+    };
+    MultiExpose::<dyn PayloadView<char>>::add_viewer(&mut exposed, Box::new(Count(0))).unwrap();
+
+    assert_eq!(
+        exposed.send(Payload('x')).unwrap_continue(),
+        ['x', 'x', 'x']
+    );
+    assert_eq!(exposed.viewers.len(), 1);
+
+    let empty = PayloadExposed::<char, 2>::new(Repeat);
+    assert!(empty.viewers().is_empty());
+}
+
+struct Debugged<R>(R);
+
+impl<R> Debug for Debugged<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Debugged")
+    }
+}
+
+impl<E, R: Receive<E>> Receive<E> for Debugged<R> {
+    type Output = R::Output;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        self.0.send(event)
+    }
+}
@@ -0,0 +1,89 @@
+use data_router::{
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+use data_router_derive::receiver;
+
+#[derive(Debug, Default, PartialEq)]
+struct Player {
+    health: i32,
+    shielded: bool,
+    seen: usize,
+}
+
+#[receiver]
+impl Player {
+    #[receive]
+    fn on_damage(&mut self, damage: i32) -> ReceiverResult<i32, i32> {
+        if self.shielded {
+            return ReceiverResult::Delete(damage);
+        }
+        self.health -= damage;
+        ReceiverResult::Continue(self.health)
+    }
+
+    #[receive]
+    fn on_shield(&mut self, shielded: bool) {
+        self.shielded = shielded;
+    }
+
+    #[receive]
+    fn on_greet(&mut self, name: String) -> String {
+        format!("hello {}, health {}", name, self.health)
+    }
+
+    #[view]
+    fn watch(&mut self, _: &u8) {
+        self.seen += 1;
+    }
+
+    #[view]
+    fn watch_once(&mut self, _: &char) -> Option<DeleteView> {
+        Some(DeleteView)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.health <= 0
+    }
+}
+
+struct Wrapper<T>(T);
+
+#[receiver]
+impl<T: Clone> Wrapper<T> {
+    #[receive]
+    fn replace(&mut self, value: T) -> T {
+        std::mem::replace(&mut self.0, value)
+    }
+}
+
+#[test]
+fn receiver_impls() {
+    let mut player = Player {
+        health: 10,
+        ..Default::default()
+    };
+
+    assert_eq!(player.send(4).unwrap_continue(), 6);
+    player.send(true).unwrap_continue();
+    assert_eq!(player.send(4), ReceiverResult::Delete(4));
+    assert_eq!(
+        player.send("bob".to_string()).unwrap_continue(),
+        "hello bob, health 6"
+    );
+    player.send(false).unwrap_continue();
+    player.send(6).unwrap_continue();
+    assert!(player.is_dead());
+
+    assert!(player.view(&1u8).is_none());
+    assert!(player.view(&2u8).is_none());
+    assert!(player.view(&'x').is_some());
+    assert_eq!(player.seen, 2);
+}
+
+#[test]
+fn generic_receiver_impls() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.send(2).unwrap_continue(), 1);
+    assert_eq!(wrapper.0, 2);
+}
@@ -0,0 +1,21 @@
+use data_router::receive::{Receive, ReceiverResult};
+use data_router_derive::Event;
+
+#[derive(Event)]
+#[event(receiver = Player)]
+enum GameEvent {
+    Damage(i32),
+    Rename(String),
+}
+
+struct Player;
+
+impl Receive<i32> for Player {
+    type Output = ();
+
+    fn send(&mut self, _: i32) -> ReceiverResult<i32, Self::Output> {
+        ReceiverResult::Continue(())
+    }
+}
+
+fn main() {}
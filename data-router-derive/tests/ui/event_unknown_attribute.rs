@@ -0,0 +1,9 @@
+use data_router_derive::Event;
+
+#[derive(Event)]
+#[event(reciever = Player)]
+enum GameEvent {
+    Damage(i32),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use data_router_derive::Event;
+
+#[derive(Event)]
+#[event(viewer = Log)]
+enum GameEvent {
+    Damage(i32),
+    Move(i32, i32),
+}
+
+struct Log;
+
+fn main() {}
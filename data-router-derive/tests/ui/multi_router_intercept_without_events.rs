@@ -0,0 +1,13 @@
+use data_router::multi_router::multi_router_intercept_trait;
+use data_router_derive::MultiRouter;
+
+multi_router_intercept_trait!(LifeIntercept for i32 | bool);
+
+#[derive(MultiRouter)]
+struct LifeRouter<R> {
+    #[multi_router(intercept = LifeIntercept)]
+    life: Option<Box<dyn LifeIntercept>>,
+    receiver: R,
+}
+
+fn main() {}
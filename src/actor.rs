@@ -0,0 +1,132 @@
+use std::thread::{self, JoinHandle};
+
+use crate::{
+    channel::mailbox::{mailbox, MailboxSender},
+    receive::{Receive, ReceiverResult},
+    reply::{reply, Reply, Responder},
+};
+
+struct Mail<E, O> {
+    event: E,
+    responder: Responder<ReceiverResult<E, O>>,
+}
+
+pub struct ActorHandle<E, O> {
+    mailbox: MailboxSender<Mail<E, O>>,
+}
+
+impl<E, O> Receive<E> for ActorHandle<E, O> {
+    type Output = Reply<ReceiverResult<E, O>>;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        let (responder, reply) = reply();
+        match self.mailbox.send(Mail { event, responder }) {
+            Ok(()) => ReceiverResult::Continue(reply),
+            Err(mail) => ReceiverResult::Delete(mail.event),
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.mailbox.is_closed()
+    }
+}
+
+impl<E, O> Clone for ActorHandle<E, O> {
+    fn clone(&self) -> Self {
+        Self {
+            mailbox: self.mailbox.clone(),
+        }
+    }
+}
+
+impl<E, O> std::fmt::Debug for ActorHandle<E, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_alive() {
+            true => write!(f, "ActorHandle"),
+            false => write!(f, "ActorHandle(stopped)"),
+        }
+    }
+}
+
+pub struct Actor<E, R: Receive<E>> {
+    mailbox: MailboxSender<Mail<E, R::Output>>,
+    thread: Option<JoinHandle<R>>,
+}
+
+impl<E: Send + 'static, R: Receive<E> + Send + 'static> Actor<E, R>
+where
+    R::Output: Send + 'static,
+{
+    /// moves `receiver` to a new thread, handles block while `capacity` events, at least one, are waiting
+    pub fn spawn(mut receiver: R, capacity: usize) -> Self {
+        let (mailbox, mail) = mailbox::<Mail<E, R::Output>>(Some(capacity));
+
+        let thread = thread::spawn(move || {
+            // every accepted event is handled, a dropped reply only means nobody waits for the result
+            while let Some(Mail { event, responder }) = mail.recv() {
+                let result = receiver.send(event);
+                if result.is_delete() {
+                    // closed before replying so the sender sees the deletion straight away
+                    let queued = mail.close_and_drain();
+                    let _ = responder.send(result);
+                    reject(queued);
+                    return receiver;
+                }
+                let _ = responder.send(result);
+            }
+
+            // a closed mailbox still hands out the mail sent before, so this is only reached once it is empty
+            reject(mail.close_and_drain());
+            receiver
+        });
+
+        Self {
+            mailbox,
+            thread: Some(thread),
+        }
+    }
+}
+
+// the queued mail gets its event back, like a send to a stopped actor
+fn reject<E, O>(queued: impl IntoIterator<Item = Mail<E, O>>) {
+    for Mail { event, responder } in queued {
+        let _ = responder.send(ReceiverResult::Delete(event));
+    }
+}
+
+impl<E, R: Receive<E>> Actor<E, R> {
+    pub fn handle(&self) -> ActorHandle<E, R::Output> {
+        ActorHandle {
+            mailbox: self.mailbox.clone(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.mailbox.is_closed()
+    }
+
+    /// closes the mailbox, handles the events already in it then stops, giving back the receiver
+    pub fn stop(mut self) -> thread::Result<R> {
+        self.mailbox.close();
+        self.thread.take().unwrap().join()
+    }
+}
+
+/// stops the actor like [`stop`][`Actor::stop`], dropping the receiver
+impl<E, R: Receive<E>> Drop for Actor<E, R> {
+    fn drop(&mut self) {
+        self.mailbox.close();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl<E, R: Receive<E>> std::fmt::Debug for Actor<E, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_running() {
+            true => write!(f, "Actor(running)"),
+            false => write!(f, "Actor(stopped)"),
+        }
+    }
+}
@@ -1,78 +1,34 @@
+pub mod arc_link_scope;
 pub mod arc_linked;
+pub mod arc_revoke_token;
 
-use std::{
-    ops::Deref,
-    sync::{Arc, Mutex, MutexGuard},
-};
-
-use self::arc_linked::ArcLinked;
-
-#[derive(Clone)]
-pub struct ArcLinker<R> {
-    receiver: Arc<Mutex<Option<R>>>,
-}
-
-impl<R> ArcLinker<R> {
-    pub fn new(receiver: R) -> Self {
-        Self {
-            receiver: Arc::new(Mutex::new(Some(receiver))),
-        }
-    }
-
-    pub fn get_receiver(&self) -> &Mutex<Option<R>> {
-        self
-    }
-
-    pub fn lock(&self) -> MutexGuard<Option<R>> {
-        self.receiver.lock().unwrap()
-    }
+pub use crate::linker::{link_error, lock_policy};
 
-    pub fn linked(&self) -> ArcLinked<R> {
-        ArcLinked {
-            link: self.receiver.clone(),
-        }
-    }
-}
+use std::sync::{MutexGuard, PoisonError, RwLockReadGuard, RwLockWriteGuard};
 
-impl<R> Deref for ArcLinker<R> {
-    type Target = Mutex<Option<R>>;
+use crate::linker::{
+    link_policy::{ArcMutex, ArcRwLock, LinkPolicy},
+    Linker,
+};
 
-    fn deref(&self) -> &Self::Target {
-        &self.receiver
-    }
-}
+pub type ArcLinker<R> = Linker<R, ArcMutex>;
 
-impl<R> Drop for ArcLinker<R> {
-    fn drop(&mut self) {
-        *self.receiver.lock().unwrap() = None;
-    }
-}
+pub type ArcRwLinker<R> = Linker<R, ArcRwLock>;
 
-impl<R: Default> Default for ArcLinker<R> {
-    fn default() -> Self {
-        Self::new(R::default())
+impl<R> Linker<R, ArcMutex> {
+    pub fn lock(&self) -> MutexGuard<'_, Option<R>> {
+        ArcMutex::lock(self.get_receiver())
     }
 }
 
-impl<R: std::fmt::Debug> std::fmt::Debug for ArcLinker<R> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{{links: {}, receiver: {:?}}}",
-            Arc::strong_count(&self.receiver),
-            self.receiver
-        )
+impl<R> Linker<R, ArcRwLock> {
+    pub fn read(&self) -> RwLockReadGuard<'_, Option<R>> {
+        self.get_receiver()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
     }
-}
 
-impl<R: std::fmt::Display> std::fmt::Display for ArcLinker<R> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.receiver.try_lock() {
-            Ok(lock) => match lock.deref() {
-                Some(value) => value.fmt(f),
-                None => write!(f, "<deleted>"),
-            },
-            Err(_) => write!(f, "<locked>"),
-        }
+    pub fn write(&self) -> RwLockWriteGuard<'_, Option<R>> {
+        ArcRwLock::lock(self.get_receiver())
     }
 }
@@ -0,0 +1,8 @@
+use crate::linker::{
+    link_policy::{ArcMutex, ArcRwLock},
+    link_scope::LinkScope,
+};
+
+pub type ArcLinkScope = LinkScope<ArcMutex>;
+
+pub type ArcRwLinkScope = LinkScope<ArcRwLock>;
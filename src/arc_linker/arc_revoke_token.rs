@@ -0,0 +1,3 @@
+use crate::linker::{link_policy::ArcMutex, revoke_token::RevokeToken};
+
+pub type ArcRevokeToken = RevokeToken<ArcMutex>;
@@ -0,0 +1,70 @@
+pub mod atomic_linked;
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+
+use self::atomic_linked::AtomicLinked;
+
+#[derive(Clone)]
+pub struct AtomicLinker<R> {
+    receiver: Arc<ArcSwapOption<R>>,
+}
+
+impl<R> AtomicLinker<R> {
+    pub fn new(receiver: R) -> Self {
+        Self {
+            receiver: Arc::new(ArcSwapOption::from_pointee(receiver)),
+        }
+    }
+
+    pub fn load(&self) -> Option<Arc<R>> {
+        self.receiver.load_full()
+    }
+
+    pub fn replace(&self, receiver: R) -> Option<Arc<R>> {
+        self.receiver.swap(Some(Arc::new(receiver)))
+    }
+
+    pub fn linked(&self) -> AtomicLinked<R> {
+        AtomicLinked {
+            link: self.receiver.clone(),
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.receiver.load().is_some()
+    }
+}
+
+impl<R> Drop for AtomicLinker<R> {
+    fn drop(&mut self) {
+        self.receiver.store(None);
+    }
+}
+
+impl<R: Default> Default for AtomicLinker<R> {
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
+
+impl<R: std::fmt::Debug> std::fmt::Debug for AtomicLinker<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{links: {}, receiver: {:?}}}",
+            Arc::strong_count(&self.receiver),
+            self.receiver.load()
+        )
+    }
+}
+
+impl<R: std::fmt::Display> std::fmt::Display for AtomicLinker<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.receiver.load().as_ref() {
+            Some(value) => value.fmt(f),
+            None => write!(f, "<deleted>"),
+        }
+    }
+}
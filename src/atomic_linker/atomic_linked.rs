@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+
+use crate::{
+    receive::{Receive, ReceiverResult, SharedReceive},
+    view::{DeleteView, SharedView, View},
+};
+
+#[derive(Clone)]
+pub struct AtomicLinked<R> {
+    pub(super) link: Arc<ArcSwapOption<R>>,
+}
+
+impl<R> AtomicLinked<R> {
+    pub fn load(&self) -> Option<Arc<R>> {
+        self.link.load_full()
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.link.load().is_some()
+    }
+}
+
+impl<E, R: SharedReceive<E>> SharedReceive<E> for AtomicLinked<R> {
+    type Output = R::Output;
+
+    fn send(&self, event: E) -> ReceiverResult<E, Self::Output> {
+        match self.link.load().as_ref() {
+            Some(t0) => t0.send(event),
+            None => ReceiverResult::Delete(event),
+        }
+    }
+}
+
+impl<E, R: SharedReceive<E>> Receive<E> for AtomicLinked<R> {
+    type Output = R::Output;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        SharedReceive::send(self, event)
+    }
+
+    fn is_alive(&self) -> bool {
+        AtomicLinked::is_alive(self)
+    }
+}
+
+impl<E, R: SharedView<E>> SharedView<E> for AtomicLinked<R> {
+    fn view(&self, event: &E) -> Option<DeleteView> {
+        match self.link.load().as_ref() {
+            Some(viewer) => viewer.view(event),
+            None => Some(DeleteView),
+        }
+    }
+}
+
+impl<E, R: SharedView<E>> View<E> for AtomicLinked<R> {
+    fn view(&mut self, event: &E) -> Option<DeleteView> {
+        SharedView::view(self, event)
+    }
+
+    fn is_alive(&self) -> bool {
+        AtomicLinked::is_alive(self)
+    }
+}
+
+impl<R: std::fmt::Debug> std::fmt::Debug for AtomicLinked<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{links: {}, receiver: {:?}}}",
+            Arc::strong_count(&self.link),
+            self.link.load()
+        )
+    }
+}
+
+impl<R: std::fmt::Display> std::fmt::Display for AtomicLinked<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.link.load().as_ref() {
+            Some(value) => value.fmt(f),
+            None => write!(f, "<deleted>"),
+        }
+    }
+}
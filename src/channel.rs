@@ -0,0 +1,241 @@
+pub(crate) mod mailbox;
+
+use std::{
+    sync::{
+        mpsc::{SendError, Sender, SyncSender},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::receive::{Receive, ReceiverResult};
+
+use self::mailbox::{mailbox, Close, MailboxReceiver, MailboxSender};
+
+enum ChannelKind<E> {
+    Unbounded(Sender<E>),
+    Bounded(SyncSender<E>),
+    Mailbox(MailboxSender<E>),
+}
+
+pub struct ChannelSender<E> {
+    sender: ChannelKind<E>,
+    disconnected: bool,
+}
+
+impl<E> ChannelSender<E> {
+    pub fn new(sender: Sender<E>) -> Self {
+        Self {
+            sender: ChannelKind::Unbounded(sender),
+            disconnected: false,
+        }
+    }
+
+    /// blocks while the channel is full
+    pub fn new_sync(sender: SyncSender<E>) -> Self {
+        Self {
+            sender: ChannelKind::Bounded(sender),
+            disconnected: false,
+        }
+    }
+}
+
+impl<E> Receive<E> for ChannelSender<E> {
+    type Output = ();
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        let sent = match self.sender {
+            ChannelKind::Unbounded(ref sender) => sender.send(event),
+            ChannelKind::Bounded(ref sender) => sender.send(event),
+            ChannelKind::Mailbox(ref sender) => sender.send(event).map_err(SendError),
+        };
+        match sent {
+            Ok(()) => ReceiverResult::Continue(()),
+            Err(SendError(event)) => {
+                self.disconnected = true;
+                ReceiverResult::Delete(event)
+            }
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        match self.sender {
+            ChannelKind::Mailbox(ref sender) => !self.disconnected && !sender.is_closed(),
+            _ => !self.disconnected,
+        }
+    }
+}
+
+impl<E> Clone for ChannelSender<E> {
+    fn clone(&self) -> Self {
+        let sender = match self.sender {
+            ChannelKind::Unbounded(ref sender) => ChannelKind::Unbounded(sender.clone()),
+            ChannelKind::Bounded(ref sender) => ChannelKind::Bounded(sender.clone()),
+            ChannelKind::Mailbox(ref sender) => ChannelKind::Mailbox(sender.clone()),
+        };
+        Self {
+            sender,
+            disconnected: self.disconnected,
+        }
+    }
+}
+
+impl<E> From<Sender<E>> for ChannelSender<E> {
+    fn from(sender: Sender<E>) -> Self {
+        Self::new(sender)
+    }
+}
+
+impl<E> From<SyncSender<E>> for ChannelSender<E> {
+    fn from(sender: SyncSender<E>) -> Self {
+        Self::new_sync(sender)
+    }
+}
+
+impl<E> std::fmt::Debug for ChannelSender<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.disconnected {
+            true => write!(f, "ChannelSender(disconnected)"),
+            false => write!(f, "ChannelSender"),
+        }
+    }
+}
+
+/// the receiving side of a [`channel`] or [`sync_channel`], drained by a [`RouterPump`]
+pub struct ChannelReceiver<E> {
+    mailbox: MailboxReceiver<E>,
+}
+
+impl<E> ChannelReceiver<E> {
+    /// blocks until an event is sent, returns `None` once every sender hung up
+    pub fn recv(&self) -> Option<E> {
+        self.mailbox.recv()
+    }
+}
+
+impl<E> std::fmt::Debug for ChannelReceiver<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChannelReceiver")
+    }
+}
+
+/// a channel for a [`RouterPump`], unlike an `mpsc` one it lets the pump stop waiting as soon as it is shut down
+pub fn channel<E>() -> (ChannelSender<E>, ChannelReceiver<E>) {
+    let (sender, mailbox) = mailbox(None);
+    (
+        ChannelSender {
+            sender: ChannelKind::Mailbox(sender),
+            disconnected: false,
+        },
+        ChannelReceiver { mailbox },
+    )
+}
+
+/// like [`channel`], senders block while `bound` events, at least one, are waiting
+pub fn sync_channel<E>(bound: usize) -> (ChannelSender<E>, ChannelReceiver<E>) {
+    let (sender, mailbox) = mailbox(Some(bound));
+    (
+        ChannelSender {
+            sender: ChannelKind::Mailbox(sender),
+            disconnected: false,
+        },
+        ChannelReceiver { mailbox },
+    )
+}
+
+pub struct RouterPump<R> {
+    events: Arc<dyn Close>,
+    thread: Option<JoinHandle<R>>,
+}
+
+impl<R: Send + 'static> RouterPump<R> {
+    /// sends every event from `events` to `receiver` on a new thread
+    pub fn spawn<E: Send + 'static>(receiver: R, events: ChannelReceiver<E>) -> Self
+    where
+        R: Receive<E>,
+    {
+        Self::spawn_inner(receiver, events, drop)
+    }
+
+    /// like [`spawn`][`RouterPump::spawn`], also sending every output to `replies`
+    pub fn spawn_with_replies<E: Send + 'static>(
+        receiver: R,
+        events: ChannelReceiver<E>,
+        replies: Sender<R::Output>,
+    ) -> Self
+    where
+        R: Receive<E>,
+        R::Output: Send + 'static,
+    {
+        Self::spawn_inner(receiver, events, move |output| {
+            let _ = replies.send(output);
+        })
+    }
+
+    fn spawn_inner<E: Send + 'static>(
+        mut receiver: R,
+        events: ChannelReceiver<E>,
+        mut on_output: impl FnMut(R::Output) + Send + 'static,
+    ) -> Self
+    where
+        R: Receive<E>,
+    {
+        let closer = events.mailbox.closer();
+
+        let thread = thread::spawn(move || {
+            // once closed the channel only hands out the events sent before, so the drain is bounded
+            while let Some(event) = events.recv() {
+                match receiver.send(event) {
+                    ReceiverResult::Continue(output) => on_output(output),
+                    ReceiverResult::Stop => {}
+                    ReceiverResult::Delete(_) => break,
+                }
+            }
+
+            receiver
+        });
+
+        Self {
+            events: closer,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl<R> RouterPump<R> {
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
+
+    /// waits for every sender to hang up or the receiver to ask to be deleted, giving back the receiver
+    pub fn join(mut self) -> thread::Result<R> {
+        self.thread.take().unwrap().join()
+    }
+
+    /// closes the channel, sends the events already in it then stops, giving back the receiver
+    pub fn shutdown(mut self) -> thread::Result<R> {
+        self.events.close();
+        self.thread.take().unwrap().join()
+    }
+}
+
+/// shuts the pump down, dropping the receiver
+impl<R> Drop for RouterPump<R> {
+    fn drop(&mut self) {
+        self.events.close();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl<R> std::fmt::Debug for RouterPump<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match !self.is_running() {
+            true => write!(f, "RouterPump(finished)"),
+            false => write!(f, "RouterPump(running)"),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+};
+
+struct State<T> {
+    queue: VecDeque<T>,
+    closed: bool,
+    senders: usize,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    // notified when an item is queued, the mailbox is closed or the last sender hangs up
+    queued: Condvar,
+    // notified when an item is taken or the mailbox is closed
+    taken: Condvar,
+    capacity: Option<usize>,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.queued.notify_all();
+        self.taken.notify_all();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+}
+
+/// closes a mailbox without knowing the type of its items
+pub(crate) trait Close: Send + Sync {
+    fn close(&self);
+}
+
+impl<T: Send> Close for Shared<T> {
+    fn close(&self) {
+        Shared::close(self)
+    }
+}
+
+/// a queue that, unlike an `mpsc` channel, can be closed from either side, waking everything blocked on it
+pub(crate) fn mailbox<T>(capacity: Option<usize>) -> (MailboxSender<T>, MailboxReceiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            closed: false,
+            senders: 1,
+        }),
+        queued: Condvar::new(),
+        taken: Condvar::new(),
+        capacity: capacity.map(|capacity| capacity.max(1)),
+    });
+    (
+        MailboxSender {
+            shared: shared.clone(),
+        },
+        MailboxReceiver { shared },
+    )
+}
+
+pub(crate) struct MailboxSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> MailboxSender<T> {
+    /// blocks while the mailbox is full, hands the item back once it is closed
+    pub(crate) fn send(&self, item: T) -> Result<(), T> {
+        let mut state = self.shared.lock();
+        if let Some(capacity) = self.shared.capacity {
+            state = self
+                .shared
+                .taken
+                .wait_while(state, |state| {
+                    !state.closed && state.queue.len() >= capacity
+                })
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+
+        if state.closed {
+            return Err(item);
+        }
+        state.queue.push_back(item);
+        drop(state);
+        self.shared.queued.notify_one();
+        Ok(())
+    }
+
+    pub(crate) fn close(&self) {
+        self.shared.close()
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.shared.is_closed()
+    }
+}
+
+impl<T> Clone for MailboxSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for MailboxSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            self.shared.queued.notify_all();
+        }
+    }
+}
+
+pub(crate) struct MailboxReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> MailboxReceiver<T> {
+    /// blocks until an item is queued, returns `None` once the mailbox is empty and either closed or every sender
+    /// hung up
+    pub(crate) fn recv(&self) -> Option<T> {
+        let mut state = self
+            .shared
+            .queued
+            .wait_while(self.shared.lock(), |state| {
+                state.queue.is_empty() && !state.closed && state.senders > 0
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let item = state.queue.pop_front();
+        drop(state);
+        if item.is_some() {
+            self.shared.taken.notify_one();
+        }
+        item
+    }
+
+    /// closes the mailbox and takes out the items still queued
+    pub(crate) fn close_and_drain(&self) -> VecDeque<T> {
+        let queue = {
+            let mut state = self.shared.lock();
+            state.closed = true;
+            std::mem::take(&mut state.queue)
+        };
+        self.shared.queued.notify_all();
+        self.shared.taken.notify_all();
+        queue
+    }
+
+    pub(crate) fn closer(&self) -> Arc<dyn Close>
+    where
+        T: Send + 'static,
+    {
+        self.shared.clone()
+    }
+}
+
+impl<T> Drop for MailboxReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.close()
+    }
+}
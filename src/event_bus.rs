@@ -0,0 +1,244 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use crate::{
+    exposed::ExposedBase,
+    receive::{pass_receiver::PassReceiver, Receive, ReceiverResult},
+    router::{
+        route_policy::{
+            IntoIntercept, IntoViewer, LocalRoutes, RoutePolicy, SendRoutes, SyncRoutes,
+        },
+        Route, RouterBase,
+    },
+};
+
+pub type BusRouterBase<E, P> = RouterBase<'static, E, ExposedBase<'static, E, PassReceiver, P>, P>;
+
+pub type BusRouter<E> = BusRouterBase<E, LocalRoutes>;
+
+pub type EventBus = EventBusBase<LocalRoutes>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Published<E> {
+    /// nothing alive was subscribed to or intercepting the event type
+    Unhandled(E),
+    /// the event passed every intercept and viewer
+    Handled(E),
+    /// an intercept stopped the event
+    Stopped,
+}
+
+impl<E> Published<E> {
+    pub fn is_handled(&self) -> bool {
+        !matches!(self, Self::Unhandled(_))
+    }
+
+    pub fn into_event(self) -> Option<E> {
+        match self {
+            Self::Unhandled(event) | Self::Handled(event) => Some(event),
+            Self::Stopped => None,
+        }
+    }
+
+    pub(crate) fn from_result(result: ReceiverResult<E, E>, handled: bool) -> Self {
+        match result {
+            ReceiverResult::Continue(event) | ReceiverResult::Delete(event) if handled => {
+                Self::Handled(event)
+            }
+            ReceiverResult::Continue(event) | ReceiverResult::Delete(event) => {
+                Self::Unhandled(event)
+            }
+            ReceiverResult::Stop => Self::Stopped,
+        }
+    }
+}
+
+/// sends `event` through an exposed router and reports whether a live intercept or viewer saw it
+pub(crate) fn send_exposed<'a, E, R: Receive<E, Output = E>, P: RoutePolicy<'a, E>>(
+    router: &mut RouterBase<'a, E, ExposedBase<'a, E, R, P>, P>,
+    event: E,
+) -> (ReceiverResult<E, E>, bool) {
+    // dead intercepts and viewers are otherwise only removed while the event passes them, so they are removed
+    // first for the event not to count as handled by them
+    router.collect_garbage();
+    let handled =
+        router.get_intercept().is_some() || !router.get_receiver().get_viewers().is_empty();
+    (router.send(event), handled)
+}
+
+pub trait AnyRouter {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn collect_garbage(&mut self) -> usize;
+}
+
+impl<E: 'static, P: RoutePolicy<'static, E> + 'static> AnyRouter for BusRouterBase<E, P> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn collect_garbage(&mut self) -> usize {
+        RouterBase::collect_garbage(self)
+    }
+}
+
+/// chooses how an [`EventBusBase`] boxes its routers, which decides whether the bus is `Send` or `Sync`
+pub trait BusPolicy: Sized + 'static {
+    type Router: ?Sized + AnyRouter;
+
+    fn boxed_router<E: 'static>(router: BusRouterBase<E, Self>) -> Box<Self::Router>
+    where
+        Self: RoutePolicy<'static, E>;
+}
+
+impl BusPolicy for LocalRoutes {
+    type Router = dyn AnyRouter;
+
+    fn boxed_router<E: 'static>(router: BusRouterBase<E, Self>) -> Box<Self::Router> {
+        Box::new(router)
+    }
+}
+
+impl BusPolicy for SendRoutes {
+    type Router = dyn AnyRouter + Send;
+
+    fn boxed_router<E: 'static>(router: BusRouterBase<E, Self>) -> Box<Self::Router> {
+        Box::new(router)
+    }
+}
+
+impl BusPolicy for SyncRoutes {
+    type Router = dyn AnyRouter + Send + Sync;
+
+    fn boxed_router<E: 'static>(router: BusRouterBase<E, Self>) -> Box<Self::Router> {
+        Box::new(router)
+    }
+}
+
+pub struct EventBusBase<P: BusPolicy> {
+    routers: HashMap<TypeId, Box<P::Router>>,
+}
+
+impl<P: BusPolicy> EventBusBase<P> {
+    pub fn new() -> Self {
+        Self {
+            routers: HashMap::new(),
+        }
+    }
+
+    pub fn router<E: 'static>(&self) -> Option<&BusRouterBase<E, P>>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.routers
+            .get(&TypeId::of::<E>())
+            .and_then(|router| router.as_any().downcast_ref())
+    }
+
+    pub fn router_mut<E: 'static>(&mut self) -> Option<&mut BusRouterBase<E, P>>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.routers
+            .get_mut(&TypeId::of::<E>())
+            .and_then(|router| router.as_any_mut().downcast_mut())
+    }
+
+    fn router_or_insert<E: 'static>(&mut self) -> &mut BusRouterBase<E, P>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.routers
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| {
+                P::boxed_router(RouterBase::<E, PassReceiver, P>::new_exposed(PassReceiver))
+            })
+            .as_any_mut()
+            .downcast_mut()
+            .unwrap()
+    }
+
+    pub fn subscribe<E: 'static>(
+        &mut self,
+        viewer: impl IntoViewer<'static, E, P>,
+    ) -> Result<usize, counted_map::HashMapFull>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.router_or_insert::<E>()
+            .get_receiver_mut()
+            .add_viewer(viewer.into_viewer())
+    }
+
+    pub fn unsubscribe<E: 'static>(&mut self, id: usize) -> Option<Box<P::Viewer>>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.router_mut::<E>()?.get_receiver_mut().remove_viewer(id)
+    }
+
+    pub fn intercept<E: 'static>(&mut self, intercept: Box<P::Intercept>)
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.router_or_insert::<E>().intercept(intercept)
+    }
+
+    pub fn intercept_from_receiver<E: 'static>(
+        &mut self,
+        intercept: impl IntoIntercept<'static, E, P>,
+    ) where
+        P: RoutePolicy<'static, E>,
+    {
+        self.router_or_insert::<E>()
+            .intercept(intercept.into_intercept())
+    }
+
+    pub fn delete_top_intercept<E: 'static>(&mut self) -> Option<Box<P::Intercept>>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        self.router_mut::<E>()?.delete_top_intercept()
+    }
+
+    pub fn publish<E: 'static>(&mut self, event: E) -> Published<E>
+    where
+        P: RoutePolicy<'static, E>,
+    {
+        match self.router_mut::<E>() {
+            Some(router) => {
+                let (result, handled) = send_exposed(router, event);
+                Published::from_result(result, handled)
+            }
+            None => Published::Unhandled(event),
+        }
+    }
+
+    pub fn collect_garbage(&mut self) -> usize {
+        self.routers
+            .values_mut()
+            .map(|router| router.collect_garbage())
+            .sum()
+    }
+}
+
+impl<P: BusPolicy> Default for EventBusBase<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: BusPolicy> std::fmt::Debug for EventBusBase<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{event types: {}}}", self.routers.len())
+    }
+}
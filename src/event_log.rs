@@ -0,0 +1,268 @@
+pub mod event_log_error;
+
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Read, Write},
+    marker::PhantomData,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::recorder::{Record, RecordSink};
+
+use self::event_log_error::EventLogError;
+
+/// the first bytes of a binary event log
+pub const MAGIC: [u8; 4] = *b"DRLG";
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// [`MAGIC`] followed by the header and records as CBOR, each prefixed by its length as a little endian `u32`
+    Binary,
+    /// the header and records as JSON, one per line
+    JsonLines,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LogHeader {
+    /// the [`FORMAT_VERSION`] the log was written with
+    pub version: u32,
+    pub event_type: String,
+    /// the version of the event type's own layout, chosen by the writer
+    pub schema_version: u32,
+}
+
+impl LogHeader {
+    /// `event_type` is a name for the events chosen by the writer, it should stay the same across builds and renames
+    pub fn new(event_type: impl Into<String>, schema_version: u32) -> Self {
+        Self {
+            version: FORMAT_VERSION,
+            event_type: event_type.into(),
+            schema_version,
+        }
+    }
+
+    /// checks that the log holds events named `event_type` with the given schema version
+    pub fn check(&self, event_type: &str, schema_version: u32) -> Result<(), EventLogError> {
+        if self.event_type != event_type {
+            return Err(EventLogError::TypeMismatch {
+                expected: event_type.to_string(),
+                found: self.event_type.clone(),
+            });
+        }
+        if self.schema_version != schema_version {
+            return Err(EventLogError::SchemaMismatch {
+                expected: schema_version,
+                found: self.schema_version,
+            });
+        }
+        Ok(())
+    }
+}
+
+pub struct EventLogWriter<W: Write, E> {
+    writer: W,
+    encoding: Encoding,
+    header: LogHeader,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<W: Write, E: Serialize> EventLogWriter<W, E> {
+    /// see [`LogHeader::new`] for `event_type`
+    pub fn new(
+        writer: W,
+        encoding: Encoding,
+        event_type: impl Into<String>,
+        schema_version: u32,
+    ) -> Result<Self, EventLogError> {
+        Self::with_header(writer, encoding, LogHeader::new(event_type, schema_version))
+    }
+
+    pub fn with_header(
+        writer: W,
+        encoding: Encoding,
+        header: LogHeader,
+    ) -> Result<Self, EventLogError> {
+        let mut log = Self {
+            writer,
+            encoding,
+            header,
+            _event: PhantomData,
+        };
+        if encoding == Encoding::Binary {
+            log.writer.write_all(&MAGIC)?;
+        }
+        let header = log.header.clone();
+        log.write_entry(&header)?;
+        Ok(log)
+    }
+
+    fn write_entry(&mut self, entry: &impl Serialize) -> Result<(), EventLogError> {
+        match self.encoding {
+            Encoding::Binary => {
+                let mut frame = Vec::new();
+                ciborium::into_writer(entry, &mut frame)
+                    .map_err(|error| EventLogError::Encoding(error.to_string()))?;
+                let length = u32::try_from(frame.len())
+                    .map_err(|_| EventLogError::Encoding("record too large".to_string()))?;
+                self.writer.write_all(&length.to_le_bytes())?;
+                self.writer.write_all(&frame)?;
+            }
+            Encoding::JsonLines => {
+                serde_json::to_writer(&mut self.writer, entry)?;
+                self.writer.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write(&mut self, record: &Record<E>) -> Result<(), EventLogError> {
+        self.write_entry(record)
+    }
+
+    pub fn header(&self) -> &LogHeader {
+        &self.header
+    }
+
+    pub fn flush(&mut self) -> Result<(), EventLogError> {
+        Ok(self.writer.flush()?)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, E: Serialize> RecordSink<E> for EventLogWriter<W, E> {
+    fn write(&mut self, record: Record<E>) -> bool {
+        EventLogWriter::write(self, &record).is_ok()
+    }
+}
+
+impl<W: Write, E> std::fmt::Debug for EventLogWriter<W, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{encoding: {:?}, header: {:?}}}",
+            self.encoding, self.header
+        )
+    }
+}
+
+pub struct EventLogReader<R: Read, E> {
+    reader: BufReader<R>,
+    encoding: Encoding,
+    header: LogHeader,
+    _event: PhantomData<fn() -> E>,
+}
+
+impl<R: Read, E: DeserializeOwned> EventLogReader<R, E> {
+    /// reads the header, accepting events of any type and schema
+    pub fn open(reader: R, encoding: Encoding) -> Result<Self, EventLogError> {
+        let mut reader = BufReader::new(reader);
+        if encoding == Encoding::Binary {
+            let mut magic = [0; 4];
+            reader.read_exact(&mut magic)?;
+            if magic != MAGIC {
+                return Err(EventLogError::BadMagic);
+            }
+        }
+
+        let header: LogHeader = read_entry(&mut reader, encoding)?
+            .ok_or_else(|| EventLogError::Io(std::io::Error::from(ErrorKind::UnexpectedEof)))?;
+        if header.version > FORMAT_VERSION {
+            return Err(EventLogError::UnsupportedVersion(header.version));
+        }
+
+        Ok(Self {
+            reader,
+            encoding,
+            header,
+            _event: PhantomData,
+        })
+    }
+
+    /// like [`open`][`EventLogReader::open`], also checking the header with [`LogHeader::check`]
+    pub fn open_checked(
+        reader: R,
+        encoding: Encoding,
+        event_type: &str,
+        schema_version: u32,
+    ) -> Result<Self, EventLogError> {
+        let log = Self::open(reader, encoding)?;
+        log.header.check(event_type, schema_version)?;
+        Ok(log)
+    }
+
+    pub fn header(&self) -> &LogHeader {
+        &self.header
+    }
+
+    /// `None` once the log ended
+    pub fn read(&mut self) -> Result<Option<Record<E>>, EventLogError> {
+        read_entry(&mut self.reader, self.encoding)
+    }
+}
+
+fn read_entry<T: DeserializeOwned>(
+    reader: &mut impl BufRead,
+    encoding: Encoding,
+) -> Result<Option<T>, EventLogError> {
+    match encoding {
+        Encoding::Binary => {
+            let mut length = [0; 4];
+            let mut filled = 0;
+            while filled < length.len() {
+                match reader.read(&mut length[filled..]) {
+                    Ok(0) if filled == 0 => return Ok(None),
+                    Ok(0) => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+                    Ok(read) => filled += read,
+                    Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                    Err(error) => return Err(error.into()),
+                }
+            }
+
+            // the buffer only grows with what is actually read, so a corrupt length can't allocate it upfront
+            let length = u32::from_le_bytes(length) as usize;
+            let mut frame = Vec::new();
+            reader
+                .by_ref()
+                .take(length as u64)
+                .read_to_end(&mut frame)?;
+            if frame.len() < length {
+                return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+            }
+            ciborium::from_reader(frame.as_slice())
+                .map(Some)
+                .map_err(|error| EventLogError::Encoding(error.to_string()))
+        }
+        Encoding::JsonLines => {
+            let mut line = String::new();
+            while line.trim().is_empty() {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 {
+                    return Ok(None);
+                }
+            }
+            Ok(Some(serde_json::from_str(&line)?))
+        }
+    }
+}
+
+impl<R: Read, E: DeserializeOwned> Iterator for EventLogReader<R, E> {
+    type Item = Result<Record<E>, EventLogError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+impl<R: Read, E> std::fmt::Debug for EventLogReader<R, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{encoding: {:?}, header: {:?}}}",
+            self.encoding, self.header
+        )
+    }
+}
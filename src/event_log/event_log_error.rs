@@ -0,0 +1,63 @@
+#[derive(Debug)]
+pub enum EventLogError {
+    Io(std::io::Error),
+    /// a header or record couldn't be encoded or decoded
+    Encoding(String),
+    /// the binary log didn't start with [`MAGIC`][`super::MAGIC`]
+    BadMagic,
+    /// the log was written by a newer version of the format
+    UnsupportedVersion(u32),
+    /// the log holds events of another type
+    TypeMismatch {
+        expected: String,
+        found: String,
+    },
+    /// the log holds events of another schema version
+    SchemaMismatch {
+        expected: u32,
+        found: u32,
+    },
+}
+
+impl From<std::io::Error> for EventLogError {
+    fn from(error: std::io::Error) -> Self {
+        EventLogError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for EventLogError {
+    fn from(error: serde_json::Error) -> Self {
+        match error.io_error_kind() {
+            Some(kind) => EventLogError::Io(kind.into()),
+            None => EventLogError::Encoding(error.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for EventLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventLogError::Io(error) => write!(f, "event log io error: {}", error),
+            EventLogError::Encoding(error) => write!(f, "event log encoding error: {}", error),
+            EventLogError::BadMagic => write!(f, "not a binary event log"),
+            EventLogError::UnsupportedVersion(version) => {
+                write!(f, "unsupported event log version {}", version)
+            }
+            EventLogError::TypeMismatch { expected, found } => {
+                write!(f, "expected events of type {}, found {}", expected, found)
+            }
+            EventLogError::SchemaMismatch { expected, found } => {
+                write!(f, "expected schema version {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventLogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EventLogError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
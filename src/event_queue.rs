@@ -0,0 +1,156 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use crate::receive::{Receive, ReceiverResult};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overflow {
+    /// the new event is handed back
+    #[default]
+    Reject,
+    /// the event that would be flushed first is dropped, which is the new one if it has the highest priority
+    DropFirst,
+    /// the event that would be flushed last is dropped, which is the new one if it has the lowest priority, so
+    /// without priorities the new event is always rejected
+    DropLast,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Queued<E> {
+    Accepted,
+    /// the event was queued and this one was dropped to make room
+    Displaced(E),
+    /// the queue was full, the event wasn't queued
+    Rejected(E),
+}
+
+impl<E> Queued<E> {
+    pub fn is_accepted(&self) -> bool {
+        !matches!(self, Queued::Rejected(_))
+    }
+}
+
+pub struct EventQueue<E> {
+    events: BTreeMap<(Reverse<i64>, u64), E>,
+    next: u64,
+    priority: Option<fn(&E) -> i64>,
+    capacity: Option<(usize, Overflow)>,
+}
+
+impl<E> EventQueue<E> {
+    pub fn new() -> Self {
+        Self {
+            events: BTreeMap::new(),
+            next: 0,
+            priority: None,
+            capacity: None,
+        }
+    }
+
+    /// flushes higher priorities first, equal priorities in the order they were queued
+    pub fn with_priority(priority: fn(&E) -> i64) -> Self {
+        Self {
+            priority: Some(priority),
+            ..Self::new()
+        }
+    }
+
+    pub fn bounded(self, capacity: usize, overflow: Overflow) -> Self {
+        Self {
+            capacity: Some((capacity, overflow)),
+            ..self
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity.map(|(capacity, _)| capacity)
+    }
+
+    pub fn peek(&self) -> Option<&E> {
+        self.events.values().next()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        self.events.values()
+    }
+
+    pub fn push(&mut self, event: E) -> Queued<E> {
+        let priority = self.priority.map_or(0, |priority| priority(&event));
+        let key = (Reverse(priority), self.next);
+
+        let mut queued = Queued::Accepted;
+        if let Some((capacity, overflow)) = self.capacity {
+            if self.events.len() >= capacity {
+                // the new event is rejected when it is the one that would be dropped
+                let displaced = match overflow {
+                    Overflow::Reject => None,
+                    Overflow::DropFirst => self
+                        .events
+                        .first_key_value()
+                        .map(|(first, _)| *first)
+                        .filter(|first| *first < key),
+                    Overflow::DropLast => self
+                        .events
+                        .last_key_value()
+                        .map(|(last, _)| *last)
+                        .filter(|last| *last > key),
+                };
+                match displaced {
+                    Some(displaced) => {
+                        queued = Queued::Displaced(self.events.remove(&displaced).unwrap())
+                    }
+                    None => return Queued::Rejected(event),
+                }
+            }
+        }
+
+        self.events.insert(key, event);
+        self.next += 1;
+        queued
+    }
+
+    pub fn pop(&mut self) -> Option<E> {
+        self.events.pop_first().map(|(_, event)| event)
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// sends every queued event to `target` in order, collecting the results
+    pub fn flush<R: Receive<E>>(&mut self, target: &mut R) -> Vec<ReceiverResult<E, R::Output>> {
+        std::mem::take(&mut self.events)
+            .into_values()
+            .map(|event| target.send(event))
+            .collect()
+    }
+}
+
+impl<E> Receive<E> for EventQueue<E> {
+    type Output = Queued<E>;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        ReceiverResult::Continue(self.push(event))
+    }
+}
+
+impl<E> Default for EventQueue<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: std::fmt::Debug> std::fmt::Debug for EventQueue<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.events.values()).finish()
+    }
+}
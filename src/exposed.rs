@@ -2,15 +2,20 @@ use counted_map::ReassignableCountedMap;
 
 use crate::{
     receive::{Receive, ReceiverResult},
+    router::route_policy::{LocalRoutes, RoutePolicy, ViewerPolicy},
     view::View,
 };
 
-pub struct Exposed<E, R: Receive<E>> {
-    viewers: ReassignableCountedMap<usize, Box<dyn View<E>>>,
+pub struct ExposedBase<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> {
+    viewers: ReassignableCountedMap<usize, Box<P::Viewer>>,
     receiver: R,
 }
 
-impl<E, R: Receive<E>> Exposed<E, R> {
+/// an exposed receiver without any bounds on its viewers, see [`SendExposed`][`crate::send_exposed::SendExposed`]
+/// and [`SyncExposed`][`crate::send_exposed::SyncExposed`] for ones that can cross threads
+pub type Exposed<'a, E, R> = ExposedBase<'a, E, R, LocalRoutes>;
+
+impl<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> ExposedBase<'a, E, R, P> {
     pub fn new(receiver: R) -> Self {
         Self {
             viewers: ReassignableCountedMap::new(),
@@ -19,7 +24,7 @@ impl<E, R: Receive<E>> Exposed<E, R> {
     }
 
     pub fn with_viewers(
-        viewers: ReassignableCountedMap<usize, Box<dyn View<E>>>,
+        viewers: ReassignableCountedMap<usize, Box<P::Viewer>>,
         receiver: R,
     ) -> Self {
         Self { viewers, receiver }
@@ -33,30 +38,45 @@ impl<E, R: Receive<E>> Exposed<E, R> {
         &mut self.receiver
     }
 
-    pub fn get_viewers(&self) -> &ReassignableCountedMap<usize, Box<dyn View<E>>> {
+    pub fn get_viewers(&self) -> &ReassignableCountedMap<usize, Box<P::Viewer>> {
         &self.viewers
     }
 
-    pub fn add_viewer(
-        &mut self,
-        other: Box<dyn View<E>>,
-    ) -> Result<usize, counted_map::HashMapFull> {
+    pub fn add_viewer(&mut self, other: Box<P::Viewer>) -> Result<usize, counted_map::HashMapFull> {
         self.viewers.push(other)
     }
 
-    pub fn box_and_add_viewer(
+    pub fn box_and_add_viewer<V: View<E>>(
         &mut self,
-        other: impl View<E> + 'static,
-    ) -> Result<usize, counted_map::HashMapFull> {
-        self.add_viewer(Box::new(other))
+        other: V,
+    ) -> Result<usize, counted_map::HashMapFull>
+    where
+        P: ViewerPolicy<'a, E, V>,
+    {
+        self.add_viewer(P::boxed_viewer(other))
     }
 
-    pub fn remove_viewer(&mut self, id: usize) -> Option<Box<dyn View<E>>> {
+    pub fn remove_viewer(&mut self, id: usize) -> Option<Box<P::Viewer>> {
         self.viewers.remove(id)
     }
+
+    pub fn collect_garbage(&mut self) -> usize {
+        let dead: Vec<usize> = self
+            .viewers
+            .iter()
+            .filter(|(_, viewer)| !viewer.is_alive())
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in &dead {
+            self.viewers.remove(*id);
+        }
+
+        dead.len() + self.receiver.collect_garbage()
+    }
 }
 
-impl<E, R: Receive<E>> Receive<E> for Exposed<E, R> {
+impl<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> Receive<E> for ExposedBase<'a, E, R, P> {
     type Output = R::Output;
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
         let mut deleted = Vec::new();
@@ -73,15 +93,25 @@ impl<E, R: Receive<E>> Receive<E> for Exposed<E, R> {
 
         self.receiver.send(event)
     }
+
+    fn is_alive(&self) -> bool {
+        self.receiver.is_alive()
+    }
+
+    fn collect_garbage(&mut self) -> usize {
+        ExposedBase::collect_garbage(self)
+    }
 }
 
-impl<E, R: Receive<E> + Default> Default for Exposed<E, R> {
+impl<'a, E, R: Receive<E> + Default, P: RoutePolicy<'a, E>> Default for ExposedBase<'a, E, R, P> {
     fn default() -> Self {
         Self::new(R::default())
     }
 }
 
-impl<E, R: Receive<E> + std::fmt::Debug> std::fmt::Debug for Exposed<E, R> {
+impl<'a, E, R: Receive<E> + std::fmt::Debug, P: RoutePolicy<'a, E>> std::fmt::Debug
+    for ExposedBase<'a, E, R, P>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
@@ -92,7 +122,9 @@ impl<E, R: Receive<E> + std::fmt::Debug> std::fmt::Debug for Exposed<E, R> {
     }
 }
 
-impl<E, R: Receive<E> + std::fmt::Display> std::fmt::Display for Exposed<E, R> {
+impl<'a, E, R: Receive<E> + std::fmt::Display, P: RoutePolicy<'a, E>> std::fmt::Display
+    for ExposedBase<'a, E, R, P>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.receiver.fmt(f)
     }
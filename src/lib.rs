@@ -13,23 +13,89 @@
 //! - [`Continue`][`receive::ReceiverResult::Continue`]: continue processing the output as normal (like [`Some`])
 //! - [`Stop`][`receive::ReceiverResult::Stop`]: stop processing the output (like [`None`]`)
 //! - [`Delete`][`receive::ReceiverResult::Delete`]: gives back the event with the flag that the receiver should be
-//! deleted, this is specifically for communication with routers so that intercepts can be cleanly destructed while
-//! letting the event pass through
+//!   deleted, this is specifically for communication with routers so that intercepts can be cleanly destructed while
+//!   letting the event pass through
 //!
 //! The different ways to store receivers and viewers are:
 //! - [`RcLinker`][`rc_linker::RcLinker`] and [`ArcLinker`][`arc_linker::ArcLinker`]:
-//! a smart pointer that will mark any instances of [`RcLinked`][`rc_linker::rc_linked::RcLinked`] or
-//! [`ArcLinked`][`arc_linker::arc_linked::ArcLinked`] ready for deletion when dropped, cleaning up any
-//! dangling references.
+//!   a smart pointer that will mark any instances of [`RcLinked`][`rc_linker::rc_linked::RcLinked`] or
+//!   [`ArcLinked`][`arc_linker::arc_linked::ArcLinked`] ready for deletion when dropped, cleaning up any
+//!   dangling references. Individual handles can also be revoked through the token returned by
+//!   [`linked_revocable`][`linker::Linker::linked_revocable`]. Both are flavours of the generic
+//!   [`Linker`][`linker::Linker`], which is parameterised by a [`LinkPolicy`][`linker::link_policy::LinkPolicy`]
+//!   choosing the pointer and lock, so code written over any `Linker<R, P>` works with every flavour. The
+//!   [`LockPolicy`][`linker::lock_policy::LockPolicy`] of a linker decides what happens to poisoned or busy locks,
+//!   plain `Mutex` and `RwLock` receivers always block and recover from poisoning.
+//! - [`AtomicLinker`][`atomic_linker::AtomicLinker`]: a lock-free linker for receivers implementing
+//!   [`SharedReceive`][`receive::SharedReceive`] or [`SharedView`][`view::SharedView`], the receiver is swapped
+//!   atomically so [`AtomicLinked`][`atomic_linker::atomic_linked::AtomicLinked`] handles never block.
+//! - [`LinkScope`][`linker::link_scope::LinkScope`]: a group of linked handles that are all revoked together when the
+//!   scope is closed or dropped, scopes can be nested to revoke smaller groups. It works with any
+//!   [`LinkPolicy`][`linker::link_policy::LinkPolicy`], [`RcLinkScope`][`rc_linker::rc_link_scope::RcLinkScope`] and
+//!   [`ArcLinkScope`][`arc_linker::arc_link_scope::ArcLinkScope`] name the common ones.
 //! - [`Exposed`][`exposed::Exposed`]: a container for a receiver that allows multiple [`View`][`view::View`]ers to be
-//! prepended
+//!   prepended
 //! - [`Router`][`router::Router`]: a container for a receiver that allows another router to intercept the event at
-//! the beginning, by repeating the intercept function it will be delegated to lower routers, allowing a level of
-//! abstraction where an intercept does what is expected without breaking the rest of the router.
+//!   the beginning, by repeating the intercept function it will be delegated to lower routers, allowing a level of
+//!   abstraction where an intercept does what is expected without breaking the rest of the router.
+//! - [`SendRouter`][`send_router::SendRouter`] and [`SendExposed`][`send_exposed::SendExposed`]: the same containers
+//!   with `Send` intercepts and viewers, so routers built from thread-safe links can be moved to other threads,
+//!   [`SyncRouter`][`send_router::SyncRouter`] and [`SyncExposed`][`send_exposed::SyncExposed`] can also be shared
+//!   between them. All of them are [`RouterBase`][`router::RouterBase`] or [`ExposedBase`][`exposed::ExposedBase`]
+//!   with a different [`RoutePolicy`][`router::route_policy::RoutePolicy`] boxing the intercepts and viewers.
+//! - [`multi_router!`] and [`multi_exposed!`]: generate a router or exposed container over several event types,
+//!   each group of events sharing its own intercept or viewer trait. [`multi_exposed_router!`] combines both, giving
+//!   each group an intercept stack and a viewer set, events pass the intercepts, then the viewers, then the receiver.
+//! - [`StackRouter`][`stack_router::StackRouter`]: the macro free equivalent of [`multi_router!`], generic over a
+//!   struct of [`EventStack`][`stack_router::EventStack`]s that implements [`HasStack`][`stack_router::HasStack`] once
+//!   per event type, so a multi-event router is an ordinary type.
+//!   With the `derive` feature, `#[derive(MultiRouter)]` and `#[derive(MultiExposed)]` generate the same impls and
+//!   inherent api for a user defined struct. The macros only generate a `struct Name<R>`, a router with more generic
+//!   parameters, bounds or extra fields needs the derives.
+//! - [`EventBus`][`event_bus::EventBus`] and [`SendEventBus`][`send_event_bus::SendEventBus`], aliases of one
+//!   [`EventBusBase`][`event_bus::EventBusBase`] like the routers: keep an exposed router per event type, created
+//!   on first use, so any `'static` type can be subscribed to, intercepted and published at runtime,
+//!   [`Published`][`event_bus::Published`] reports whether anything handled the event.
+//! - [`TopicRouter`][`topic_router::TopicRouter`]: an exposed router per topic pattern like `player.*.damage` or
+//!   `player.#`, stored in a trie keyed by the `.` separated segments. A published event passes every matching
+//!   pattern, literal segments before `*` before `#`, so lookups only depend on the depth of the topic.
+//! - [`EventQueue`][`event_queue::EventQueue`]: a receiver that buffers events instead of dispatching them, in FIFO
+//!   or priority order with an optional capacity and [`Overflow`][`event_queue::Overflow`] policy, until they are
+//!   [`flush`][`event_queue::EventQueue::flush`]ed into another receiver at a well-defined point.
+//! - [`ChannelSender`][`channel::ChannelSender`] and [`RouterPump`][`channel::RouterPump`]: a receiver forwarding
+//!   events into an `mpsc` or [`channel`][`channel::channel`], returning [`Delete`][`receive::ReceiverResult::Delete`]
+//!   once the other side hangs up, and a thread that drains a [`channel`][`channel::channel`] into a receiver,
+//!   optionally replying with its outputs. Shutting down or dropping the pump closes the channel, so only the events
+//!   already sent are drained.
+//! - [`Actor`][`actor::Actor`]: a receiver owned by a dedicated thread, reached through cloneable
+//!   [`ActorHandle`][`actor::ActorHandle`]s that queue events into a bounded mailbox and return a
+//!   [`Reply`][`reply::Reply`] for the output. Once the actor stops, or its receiver asks to be deleted, handles
+//!   return [`Delete`][`receive::ReceiverResult::Delete`] like a dropped linker, and so do the replies of the events
+//!   that were still queued.
+//! - [`Reply`][`reply::Reply`]: a oneshot handle for an output computed elsewhere, waited on by blocking, with a
+//!   timeout or as a [`Future`][`std::future::Future`]. [`Deferred`][`reply::Deferred`] turns any receiver into one
+//!   that runs on a worker thread, handling the events in the order they were sent, and replies, dropping the reply
+//!   cancels its event unless it was detached. An [`Actor`][`actor::Actor`] handles every event it accepted.
+//! - [`Recorder`][`recorder::Recorder`] and [`Replayer`][`replayer::Replayer`]: a viewer or pass-through intercept
+//!   writing every event with its sequence number and time to a [`RecordSink`][`recorder::RecordSink`], or wrapping
+//!   the target receiver as [`Recorded`][`recorder::Recorded`] to also record the outcomes, and a replayer sending a
+//!   recording back into a receiver, as fast as possible or following a [`Clock`][`replayer::Clock`], checking that
+//!   each recorded [`Outcome`][`recorder::Outcome`] is reproduced.
+//! - [`EventLogWriter`][`event_log::EventLogWriter`] and [`EventLogReader`][`event_log::EventLogReader`] (`serde`
+//!   feature): a versioned, length-prefixed recording file in CBOR or JSON lines, starting with a header holding a
+//!   stable name for the event type, chosen by the writer, and its schema version. The writer is a [`RecordSink`][`recorder::RecordSink`], the reader feeds a
+//!   [`Replayer`][`replayer::Replayer`]. Events, results and errors derive `Serialize` and `Deserialize`.
+//! - `#[derive(Event)]` (`derive` feature): implements [`Receive`][`receive::Receive`] and [`View`][`view::View`] of
+//!   an event enum for a receiver, dispatching each variant's payload to the receiver's impl for that payload.
+//! - `#[receiver]` (`derive` feature): on an inherent impl block, turns each `#[receive]` or `#[view]` method into a
+//!   [`Receive`][`receive::Receive`] or [`View`][`view::View`] impl for its event type.
 //!
 //! ## Aproach
 //!
-//! The receivers function as a sort of lazy garbage collector.
+//! The receivers function as a sort of lazy garbage collector, dead links are removed when the next event reaches
+//! them. Idle routers can be pruned eagerly with [`collect_garbage`][`receive::Receive::collect_garbage`], which probes
+//! [`is_alive`][`receive::Receive::is_alive`] without dispatching an event and returns how many entries were removed,
+//! a router collects its intercepts and whatever its receiver holds, like the viewers of an exposed receiver.
 //!
 //! When receivers flags that they wish to be deleted ([`Delete`][`receive::ReceiverResult::Delete`]), it should
 //! be expected that everything occuring before the introduction of the flag was ran, including viewers and intercepters.
@@ -44,18 +110,39 @@
 //! have ran* and responsibilty falls upon the receiver to *exit the event propgation with minimal impact*.**
 //!
 
+pub mod actor;
 pub mod arc_linker;
+pub mod atomic_linker;
+pub mod channel;
+pub mod event_bus;
+#[cfg(feature = "serde")]
+pub mod event_log;
+pub mod event_queue;
 pub mod exposed;
+pub mod link_id;
+pub mod linker;
 pub mod multi_exposed;
+pub mod multi_exposed_router;
 pub mod multi_router;
 pub mod rc_linker;
 pub mod receive;
+pub mod recorder;
+pub mod replayer;
+pub mod reply;
 pub mod router;
+pub mod send_event_bus;
+pub mod send_exposed;
+pub mod send_router;
+pub mod stack_router;
+pub mod topic_router;
 pub mod view;
 
 pub use crate as event_horizon;
 pub use counted_map;
 
+#[cfg(feature = "derive")]
+pub use data_router_derive::{receiver, Event, MultiExposed, MultiRouter};
+
 #[cfg(test)]
 mod tests {
     use std::{cell::RefCell, rc::Rc, thread};
@@ -610,4 +697,1602 @@ mod tests {
             "recieved event: no intercept"
         );
     }
+
+    #[test]
+    fn linker_liveness() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use crate::arc_linker::ArcLinker;
+
+        struct Counter(i32);
+        impl Receive<i32> for Counter {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        let dropped = Rc::new(RefCell::new(None));
+        let unlinked = Rc::new(RefCell::new(0));
+
+        let linker = RcLinker::new(Counter(0));
+        let dropped_hook = dropped.clone();
+        linker.on_drop(move |counter: Counter| *dropped_hook.borrow_mut() = Some(counter.0));
+        let unlinked_hook = unlinked.clone();
+        linker.on_unlinked(move || *unlinked_hook.borrow_mut() += 1);
+
+        assert_eq!(linker.link_count(), 0);
+
+        let mut linked = linker.linked();
+        let cloned = linked.clone();
+
+        assert_eq!(linker.link_count(), 2);
+        assert!(linked.is_alive());
+        assert_eq!(linked.send(5).unwrap_continue(), 5);
+
+        drop(cloned);
+        assert_eq!(*unlinked.borrow(), 0);
+        drop(linked);
+        assert_eq!(*unlinked.borrow(), 1);
+        assert_eq!(linker.link_count(), 0);
+
+        let linked = linker.linked();
+        drop(linker);
+
+        assert!(!linked.is_alive());
+        assert_eq!(*dropped.borrow(), Some(5));
+
+        drop(linked);
+        assert_eq!(*unlinked.borrow(), 2);
+
+        let released = Arc::new(AtomicUsize::new(0));
+        let linker = ArcLinker::new(Counter(0));
+        let released_hook = released.clone();
+        linker.on_unlinked(move || {
+            released_hook.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let mut linked = linker.linked();
+        let handle = thread::spawn(move || {
+            assert!(linked.send(3).is_continue());
+            linked.is_alive()
+        });
+
+        assert!(handle.join().unwrap());
+        assert_eq!(linker.link_count(), 0);
+        assert_eq!(released.load(Ordering::SeqCst), 1);
+        assert!(linker.is_alive());
+    }
+
+    #[test]
+    fn revocable_links() {
+        struct Counter(i32);
+        impl Receive<i32> for Counter {
+            type Output = ();
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += event;
+                ReceiverResult::Continue(())
+            }
+        }
+
+        let linker = RcLinker::new(Counter(0));
+
+        let (linked_a, token_a) = linker.linked_revocable();
+        let linked_b = linker.linked();
+        let b_id = linked_b.id();
+
+        let mut router_a = Router::new(linked_a);
+        let mut router_b = Router::new(linked_b);
+
+        assert_eq!(linker.handles(), vec![token_a.id(), b_id]);
+
+        assert!(router_a.send(1).is_continue());
+        assert!(router_b.send(2).is_continue());
+        assert_eq!(linker.borrow().as_ref().unwrap().0, 3);
+
+        token_a.revoke();
+
+        assert_eq!(linker.is_revoked(token_a.id()), Some(true));
+        assert_eq!(router_a.send(10).unwrap_delete(), 10);
+        assert!(router_b.send(4).is_continue());
+        assert_eq!(linker.borrow().as_ref().unwrap().0, 7);
+
+        drop(router_a);
+
+        assert_eq!(linker.handles(), vec![b_id]);
+        assert_eq!(linker.is_revoked(token_a.id()), None);
+
+        assert!(linker.revoke(b_id));
+        assert!(router_b.send(1).is_delete());
+        assert!(linker.is_alive());
+    }
+
+    #[test]
+    fn link_scopes() {
+        use crate::{
+            arc_linker::{
+                arc_link_scope::{ArcLinkScope, ArcRwLinkScope},
+                ArcRwLinker,
+            },
+            exposed::Exposed,
+            rc_linker::rc_link_scope::RcLinkScope,
+            view::DeleteView,
+        };
+
+        struct Counter(i32);
+        impl Receive<i32> for Counter {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += event;
+                ReceiverResult::Continue(event)
+            }
+        }
+        impl View<i32> for Counter {
+            fn view(&mut self, event: &i32) -> Option<DeleteView> {
+                self.0 += event;
+                None
+            }
+        }
+
+        let shared = RcLinker::new(Counter(0));
+        let mut exposed = Exposed::new(shared.linked());
+
+        let level = RcLinkScope::new();
+        let room = level.child();
+
+        for _ in 0..3 {
+            exposed.box_and_add_viewer(level.adopt(Counter(0))).unwrap();
+        }
+        exposed.box_and_add_viewer(room.adopt(Counter(0))).unwrap();
+
+        let mut router = Router::new(exposed);
+        router.intercept_from_receiver(room.link(&shared));
+
+        assert_eq!(level.live_count(), 5);
+        assert_eq!(room.live_count(), 2);
+
+        assert!(router.send(1).is_continue());
+        assert_eq!(shared.borrow().as_ref().unwrap().0, 2);
+
+        room.close();
+
+        assert!(router.send(1).is_continue());
+        assert_eq!(router.get_receiver().get_viewers().len(), 3);
+        assert!(router.get_intercept().is_none());
+        assert_eq!(level.live_count(), 3);
+
+        drop(level);
+
+        assert!(router.send(1).is_continue());
+        assert_eq!(router.get_receiver().get_viewers().len(), 0);
+        assert!(room.is_closed());
+        assert!(room.adopt(Counter(0)).send(1).is_delete());
+
+        let scope = ArcLinkScope::new();
+        let mut linked = scope.adopt(Counter(0));
+        let handle = thread::spawn(move || linked.send(1).is_continue());
+        assert!(handle.join().unwrap());
+
+        let mut linked = scope.child().adopt(Counter(0));
+        assert!(linked.send(1).is_delete());
+        assert_eq!(scope.live_count(), 0);
+
+        let scope = ArcRwLinkScope::new();
+        let shared = ArcRwLinker::new(Counter(0));
+        let mut linked = scope.child().link(&shared);
+        let mut adopted = scope.adopt(Counter(0));
+        assert!(linked.send(1).is_delete());
+        assert!(adopted.send(1).is_continue());
+        assert_eq!(scope.live_count(), 1);
+        drop(scope);
+        assert!(adopted.send(1).is_delete());
+        assert!(shared.is_alive());
+    }
+
+    #[test]
+    fn arc_lock_policies() {
+        use std::time::Duration;
+
+        use crate::arc_linker::{
+            link_error::LinkError,
+            lock_policy::{LockPolicy, LockWait, PoisonPolicy},
+            ArcLinker,
+        };
+
+        struct Fragile(i32);
+        impl Receive<i32> for Fragile {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                assert!(event >= 0, "negative event");
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        let linker = ArcLinker::new(Fragile(0));
+
+        let mut panicking = linker.linked();
+        assert!(thread::spawn(move || panicking.send(-1)).join().is_err());
+        assert!(linker.is_poisoned());
+
+        let mut deleting = linker.linked();
+        deleting.set_policy(LockPolicy::default().with_poison(PoisonPolicy::Delete));
+        assert_eq!(deleting.send(1).unwrap_delete(), 1);
+
+        let mut propagating = linker.linked();
+        propagating.set_policy(LockPolicy::default().with_poison(PoisonPolicy::Propagate));
+        assert_eq!(propagating.try_send(2), Err(LinkError::Poisoned(2)));
+
+        let mut recovering = linker.linked();
+        assert_eq!(recovering.send(3).unwrap_continue(), 3);
+        assert!(!linker.is_poisoned());
+        assert!(propagating.send(1).is_continue());
+
+        let guard = linker.lock();
+
+        let mut trying = linker.linked();
+        trying.set_policy(LockPolicy::default().with_wait(LockWait::Try));
+        assert_eq!(trying.try_send(5), Err(LinkError::Busy(5)));
+        assert!(trying.send(5).is_stop());
+
+        let mut waiting = linker.linked();
+        waiting.set_policy(
+            LockPolicy::default().with_wait(LockWait::Timeout(Duration::from_millis(10))),
+        );
+        assert!(waiting.try_send(5).unwrap_err().is_busy());
+
+        drop(guard);
+
+        assert_eq!(waiting.try_send(5), Ok(ReceiverResult::Continue(9)));
+
+        let mut panicking = linker.linked();
+        assert!(thread::spawn(move || panicking.send(-1)).join().is_err());
+        drop(linker);
+
+        let mut plain = std::sync::Arc::new(std::sync::Mutex::new(Fragile(0)));
+        let mut panicking = plain.clone();
+        assert!(thread::spawn(move || panicking.send(-1)).join().is_err());
+        assert_eq!(plain.send(4).unwrap_continue(), 4);
+    }
+
+    #[test]
+    fn atomic_linker() {
+        use crate::{atomic_linker::AtomicLinker, receive::SharedReceive};
+
+        struct Scale(i32);
+        impl SharedReceive<i32> for Scale {
+            type Output = i32;
+
+            fn send(&self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                ReceiverResult::Continue(event * self.0)
+            }
+        }
+
+        let linker = AtomicLinker::new(Scale(2));
+
+        let handles = (0..4)
+            .map(|_| {
+                let linked = linker.linked();
+                thread::spawn(move || {
+                    (0..100).all(|i| match SharedReceive::send(&linked, i) {
+                        ReceiverResult::Continue(value) => value == i * 2 || value == i * 3,
+                        _ => false,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let old = linker.replace(Scale(3)).unwrap();
+        assert_eq!(old.0, 2);
+
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+
+        let mut router = Router::new(linker.linked());
+        assert_eq!(router.send(5).unwrap_continue(), 15);
+
+        drop(linker);
+
+        assert!(router.send(5).is_delete());
+    }
+
+    #[test]
+    fn generic_linkers() {
+        use crate::{
+            arc_linker::{ArcLinker, ArcRwLinker},
+            linker::{link_policy::LinkPolicy, Linker},
+        };
+
+        #[derive(Debug, PartialEq)]
+        struct Counter(i32);
+        impl Receive<i32> for Counter {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        fn route_twice<P: LinkPolicy + 'static>(linker: &Linker<Counter, P>) -> i32 {
+            let mut router = Router::new(linker.linked());
+            router.send(1);
+            let total = router.send(2).unwrap_continue();
+            assert_eq!(linker.link_count(), 1);
+            total
+        }
+
+        let rc = RcLinker::new(Counter(0));
+        let arc = ArcLinker::new(Counter(10));
+        let rw = ArcRwLinker::new(Counter(20));
+
+        assert_eq!(route_twice(&rc), 3);
+        assert_eq!(route_twice(&arc), 13);
+        assert_eq!(route_twice(&rw), 23);
+
+        assert_eq!(rc.borrow().as_ref(), Some(&Counter(3)));
+        assert_eq!(arc.lock().as_ref(), Some(&Counter(13)));
+        assert_eq!(rw.read().as_ref(), Some(&Counter(23)));
+
+        assert!(arc == arc.clone());
+        assert!(ArcLinker::new(Counter(1)) == ArcLinker::new(Counter(1)));
+
+        let mut linked = rw.linked();
+        let handle = thread::spawn(move || linked.send(7).unwrap_continue());
+        assert_eq!(handle.join().unwrap(), 30);
+
+        drop(rw);
+        assert!(format!("{:?}", arc).starts_with("{links: 0, "));
+    }
+
+    #[test]
+    fn self_destruct_during_dispatch() {
+        use std::sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        };
+
+        use crate::arc_linker::ArcLinker;
+
+        struct Bomb {
+            owner: Rc<RefCell<Option<RcLinker<Bomb>>>>,
+        }
+        impl Receive<i32> for Bomb {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if event == 0 {
+                    self.owner.borrow_mut().take();
+                }
+                ReceiverResult::Continue(event)
+            }
+        }
+
+        let owner = Rc::new(RefCell::new(None));
+        let linker = RcLinker::new(Bomb {
+            owner: owner.clone(),
+        });
+        let exploded = Rc::new(RefCell::new(false));
+        let flag = exploded.clone();
+        linker.on_drop(move |_| *flag.borrow_mut() = true);
+        let mut router = Router::new(linker.linked());
+        *owner.borrow_mut() = Some(linker);
+
+        assert_eq!(router.send(1).unwrap_continue(), 1);
+        assert_eq!(router.send(0).unwrap_continue(), 0);
+        assert!(*exploded.borrow());
+        assert!(router.send(2).is_delete());
+
+        struct SharedBomb {
+            owner: Arc<Mutex<Option<ArcLinker<SharedBomb>>>>,
+        }
+        impl Receive<i32> for SharedBomb {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if event == 0 {
+                    let linker = self.owner.lock().unwrap().take();
+                    drop(linker);
+                }
+                ReceiverResult::Continue(event)
+            }
+        }
+
+        let owner = Arc::new(Mutex::new(None));
+        let linker = ArcLinker::new(SharedBomb {
+            owner: owner.clone(),
+        });
+        let exploded = Arc::new(AtomicBool::new(false));
+        let flag = exploded.clone();
+        linker.on_drop(move |_| flag.store(true, Ordering::Release));
+        let mut linked = linker.linked();
+        *owner.lock().unwrap() = Some(linker);
+
+        let handle = thread::spawn(move || {
+            assert_eq!(linked.send(0).unwrap_continue(), 0);
+            linked.send(1).is_delete()
+        });
+        assert!(handle.join().unwrap());
+        assert!(exploded.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn garbage_collection() {
+        use crate::{
+            exposed::Exposed, multi_router::MultiRoute, receive::pass_receiver::PassReceiver,
+            router::Route,
+        };
+
+        mod isolated {
+            #![allow(unused)]
+
+            use crate::multi_router::{
+                impl_multi_router_intercept_trait, multi_router, multi_router_intercept_trait,
+            };
+
+            multi_router_intercept_trait!(pub CountIntercept for i32 | bool);
+
+            multi_router!(pub CountRouter {
+                i as CountIntercept where i32 | bool
+            });
+
+            impl_multi_router_intercept_trait!(CountRouter as CountIntercept for i32 | bool);
+
+            crate::multi_exposed::multi_exposed_trait!(pub CountView for i32 | bool);
+
+            crate::multi_exposed::multi_exposed!(
+                #[derive()]
+                pub CountExposed {
+                    v as CountView for i32 => i32 | bool => bool
+                } else {}
+            );
+        }
+
+        #[derive(Default)]
+        struct Counter(usize);
+        impl Receive<i32> for Counter {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += 1;
+                ReceiverResult::Continue(event)
+            }
+        }
+        impl Receive<bool> for Counter {
+            type Output = bool;
+
+            fn send(&mut self, event: bool) -> ReceiverResult<bool, Self::Output> {
+                self.0 += 1;
+                ReceiverResult::Continue(event)
+            }
+        }
+        impl<E> View<E> for Counter {
+            fn view(&mut self, _: &E) -> Option<crate::view::DeleteView> {
+                self.0 += 1;
+                None
+            }
+        }
+
+        let linkers: Vec<_> = (0..4).map(|_| RcLinker::new(Counter::default())).collect();
+
+        let mut router = Router::new(PassReceiver);
+        for linker in &linkers {
+            router.intercept_from_receiver(linker.linked());
+        }
+        let mut exposed = Exposed::<i32, _>::new(PassReceiver);
+        for linker in &linkers {
+            exposed.box_and_add_viewer(linker.linked()).unwrap();
+        }
+        let mut multi_router = isolated::CountRouter::new(PassReceiver);
+        for linker in &linkers {
+            multi_router.intercept(Box::new(isolated::CountRouter::new(linker.linked()))
+                as Box<dyn isolated::CountIntercept>);
+        }
+        let mut multi_exposed = isolated::CountExposed::new(PassReceiver);
+        for linker in &linkers {
+            crate::multi_exposed::MultiExpose::<dyn isolated::CountView>::add_viewer(
+                &mut multi_exposed,
+                Box::new(linker.linked()),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(router.collect_garbage(), 0);
+        assert_eq!(exposed.collect_garbage(), 0);
+
+        let mut linkers = linkers.into_iter();
+        let first = linkers.next().unwrap();
+        let rest: Vec<_> = linkers.skip(1).collect();
+        assert_eq!(first.link_count(), 4);
+
+        // no event has been sent, yet the dead links are pruned
+        assert_eq!(router.collect_garbage(), 1);
+        assert_eq!(exposed.collect_garbage(), 1);
+        assert_eq!(multi_router.collect_garbage(), 1);
+        assert_eq!(multi_exposed.collect_garbage(), 1);
+        assert_eq!(exposed.get_viewers().len(), 3);
+        assert_eq!(multi_exposed.v().len(), 3);
+
+        drop(first);
+        assert_eq!(router.collect_garbage(), 1);
+        assert_eq!(multi_router.collect_garbage(), 1);
+        assert_eq!(router.collect_garbage(), 0);
+
+        assert_eq!(router.send(1).unwrap_continue(), 1);
+        assert!(multi_router.send(true).unwrap_continue());
+        for linker in &rest {
+            assert_eq!(linker.borrow().as_ref().unwrap().0, 2);
+        }
+
+        drop(rest);
+        assert_eq!(router.collect_garbage(), 2);
+        assert_eq!(exposed.collect_garbage(), 3);
+        assert_eq!(multi_router.collect_garbage(), 2);
+        assert_eq!(multi_exposed.collect_garbage(), 3);
+        assert!(router.get_intercept().is_none());
+
+        // a route that doesn't collect anything itself only needs to hand out its intercepts
+        struct Forward;
+        impl Receive<i32> for Forward {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                ReceiverResult::Continue(event)
+            }
+        }
+        impl<'a> Route<'a, i32> for Forward {
+            fn intercept(&mut self, _: Box<dyn Route<'a, i32, Output = i32> + 'a>) {}
+
+            fn take_intercept(&mut self) -> Option<Box<dyn Route<'a, i32, Output = i32> + 'a>> {
+                None
+            }
+        }
+
+        let viewer = RcLinker::new(Counter::default());
+        let mut exposed_router = Router::new_exposed(PassReceiver);
+        exposed_router
+            .get_receiver_mut()
+            .box_and_add_viewer(viewer.linked())
+            .unwrap();
+        exposed_router.intercept(Box::new(Forward));
+        exposed_router.intercept(Box::new(Router::new_exposed(PassReceiver)));
+
+        drop(viewer);
+        assert_eq!(exposed_router.collect_garbage(), 1);
+        assert!(exposed_router.get_receiver().get_viewers().is_empty());
+        assert!(exposed_router.get_intercept().is_some());
+    }
+
+    #[test]
+    fn send_routers() {
+        use std::sync::mpsc;
+
+        use crate::{
+            arc_linker::ArcLinker,
+            send_router::{SendRouter, SyncRouter},
+        };
+
+        #[derive(Default)]
+        struct Total(i32);
+        impl Receive<i32> for Total {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        struct Doubler;
+        impl Receive<i32> for Doubler {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                ReceiverResult::Continue(event * 2)
+            }
+        }
+
+        struct Seen(Vec<i32>);
+        impl View<i32> for Seen {
+            fn view(&mut self, event: &i32) -> Option<crate::view::DeleteView> {
+                self.0.push(*event);
+                None
+            }
+        }
+
+        let doubler = ArcLinker::new(Doubler);
+        let seen = ArcLinker::new(Seen(Vec::new()));
+
+        let mut router = SendRouter::new_exposed(Total::default());
+        router.intercept_from_receiver(doubler.linked());
+        router
+            .get_receiver_mut()
+            .box_and_add_viewer(seen.linked())
+            .unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        sender.send(router).unwrap();
+
+        let worker = thread::spawn(move || {
+            let mut router = receiver.recv().unwrap();
+            router.send(1);
+            router.send(2);
+            router
+        });
+        let mut router = worker.join().unwrap();
+
+        assert_eq!(seen.lock().as_ref().unwrap().0, vec![2, 4]);
+
+        drop(doubler);
+        assert_eq!(router.collect_garbage(), 1);
+        assert_eq!(router.send(3).unwrap_continue(), 9);
+
+        struct Log<'l>(&'l mut Vec<i32>);
+        impl Receive<i32> for Log<'_> {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0.push(event);
+                ReceiverResult::Continue(event)
+            }
+        }
+
+        let mut log = Vec::new();
+        let mut borrowing = SendRouter::new(Total::default());
+        borrowing.intercept_from_receiver(Log(&mut log));
+        thread::scope(|scope| {
+            scope.spawn(|| borrowing.send(5));
+        });
+        drop(borrowing);
+        assert_eq!(log, vec![5]);
+
+        let mut shared = SyncRouter::new_exposed(Total::default());
+        shared
+            .get_receiver_mut()
+            .box_and_add_viewer(seen.linked())
+            .unwrap();
+        let shared = &shared;
+        thread::scope(|scope| {
+            for _ in 0..2 {
+                scope.spawn(move || assert_eq!(shared.get_receiver().get_viewers().len(), 1));
+            }
+        });
+    }
+
+    #[test]
+    fn borrowed_intercepts() {
+        use crate::exposed::Exposed;
+
+        #[derive(Default)]
+        struct Total(i32);
+        impl Receive<i32> for Total {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        struct Blocklist<'b>(&'b [i32]);
+        impl Receive<i32> for Blocklist<'_> {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if self.0.contains(&event) {
+                    ReceiverResult::Stop
+                } else {
+                    ReceiverResult::Continue(event)
+                }
+            }
+        }
+
+        struct Log<'b>(&'b mut Vec<i32>);
+        impl View<i32> for Log<'_> {
+            fn view(&mut self, event: &i32) -> Option<crate::view::DeleteView> {
+                self.0.push(*event);
+                None
+            }
+        }
+
+        fn filtered_total(events: &[i32], blocked: &[i32], log: &mut Vec<i32>) -> i32 {
+            let mut exposed = Exposed::new(Total::default());
+            exposed.box_and_add_viewer(Log(log)).unwrap();
+            let mut router = Router::with_intercept_from_receiver(Blocklist(blocked), exposed);
+
+            events
+                .iter()
+                .filter_map(|event| match router.send(*event) {
+                    ReceiverResult::Continue(total) => Some(total),
+                    _ => None,
+                })
+                .last()
+                .unwrap_or_default()
+        }
+
+        let blocked = vec![2, 4];
+        let mut log = Vec::new();
+        assert_eq!(filtered_total(&[1, 2, 3, 4, 5], &blocked, &mut log), 9);
+        assert_eq!(log, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn stack_router() {
+        use crate::stack_router::{EventStack, HasStack, StackRouter};
+
+        #[derive(Default)]
+        struct PlayerStacks {
+            damage: EventStack<i32>,
+            revive: EventStack<bool>,
+        }
+        impl HasStack<i32> for PlayerStacks {
+            fn stack(&self) -> &EventStack<i32> {
+                &self.damage
+            }
+            fn stack_mut(&mut self) -> &mut EventStack<i32> {
+                &mut self.damage
+            }
+        }
+        impl HasStack<bool> for PlayerStacks {
+            fn stack(&self) -> &EventStack<bool> {
+                &self.revive
+            }
+            fn stack_mut(&mut self) -> &mut EventStack<bool> {
+                &mut self.revive
+            }
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Player(i32);
+        impl Receive<i32> for Player {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0 = (self.0 + event).max(0);
+                ReceiverResult::Continue(self.0)
+            }
+        }
+        impl Receive<bool> for Player {
+            type Output = i32;
+
+            fn send(&mut self, event: bool) -> ReceiverResult<bool, Self::Output> {
+                if event && self.0 == 0 {
+                    self.0 = 10;
+                }
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        struct Armour(i32);
+        impl Receive<i32> for Armour {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                ReceiverResult::Continue((event + self.0).min(0))
+            }
+        }
+
+        struct Cursed;
+        impl Receive<bool> for Cursed {
+            type Output = bool;
+
+            fn send(&mut self, _: bool) -> ReceiverResult<bool, Self::Output> {
+                ReceiverResult::Stop
+            }
+        }
+
+        // routers are ordinary types, so they can be named in signatures
+        fn hit<S: HasStack<i32>, R: Receive<i32, Output = i32>>(
+            router: &mut StackRouter<S, R>,
+            amount: i32,
+        ) -> i32 {
+            router.send(-amount).unwrap_continue()
+        }
+
+        let mut router: StackRouter<PlayerStacks, Player> = StackRouter::new(Player(20));
+        assert_eq!(hit(&mut router, 5), 15);
+
+        let armour = RcLinker::new(Armour(3));
+        router.intercept_from_receiver(armour.linked());
+        assert_eq!(hit(&mut router, 5), 13);
+        assert!(router.get_intercept::<i32>().is_some());
+        assert!(router.get_intercept::<bool>().is_none());
+
+        // a whole stack router composes as an intercept of another one
+        let mut curses: StackRouter<EventStack<bool>, _> =
+            StackRouter::new(crate::receive::pass_receiver::PassReceiver);
+        let curse = RcLinker::new(Cursed);
+        curses.intercept_from_receiver(curse.linked());
+        router.intercept_from_receiver(curses);
+
+        assert_eq!(hit(&mut router, 20), 0);
+        assert!(router.send(true).is_stop());
+
+        drop(curse);
+        assert_eq!(router.send(true).unwrap_continue(), 10);
+        assert_eq!(router.collect_garbage::<bool>(), 0);
+
+        drop(armour);
+        assert_eq!(
+            crate::multi_router::MultiRoute::<dyn crate::router::Route<'static, i32, Output = i32>>::collect_garbage(
+                &mut router
+            ),
+            1
+        );
+        assert_eq!(router.get_receiver(), &Player(10));
+    }
+
+    #[test]
+    fn event_bus() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::{
+            arc_linker::ArcLinker,
+            event_bus::{EventBus, Published},
+            send_event_bus::SendEventBus,
+        };
+
+        struct Seen<E>(Vec<E>);
+        impl<E: Clone> View<E> for Seen<E> {
+            fn view(&mut self, event: &E) -> Option<crate::view::DeleteView> {
+                self.0.push(event.clone());
+                None
+            }
+        }
+
+        struct Censor;
+        impl Receive<String> for Censor {
+            type Output = String;
+
+            fn send(&mut self, event: String) -> ReceiverResult<String, Self::Output> {
+                if event.contains("secret") {
+                    ReceiverResult::Stop
+                } else {
+                    ReceiverResult::Continue(event.to_uppercase())
+                }
+            }
+        }
+
+        let numbers = RcLinker::new(Seen(Vec::new()));
+        let words = RcLinker::new(Seen(Vec::new()));
+        let censor = RcLinker::new(Censor);
+
+        let mut bus = EventBus::new();
+        assert_eq!(bus.publish(1), Published::Unhandled(1));
+
+        bus.subscribe::<i32>(numbers.linked()).unwrap();
+        bus.subscribe::<String>(words.linked()).unwrap();
+        bus.intercept_from_receiver::<String>(censor.linked());
+
+        assert_eq!(bus.publish(2), Published::Handled(2));
+        assert_eq!(bus.publish(3u8), Published::Unhandled(3));
+        assert_eq!(
+            bus.publish("hi".to_string()),
+            Published::Handled("HI".to_string())
+        );
+        assert_eq!(bus.publish("secret".to_string()), Published::Stopped);
+
+        assert_eq!(numbers.borrow().as_ref().unwrap().0, vec![2]);
+        assert_eq!(words.borrow().as_ref().unwrap().0, vec!["HI".to_string()]);
+
+        drop(numbers);
+        drop(censor);
+        assert_eq!(bus.publish(4), Published::Unhandled(4));
+        assert_eq!(bus.collect_garbage(), 1);
+        assert!(bus.publish("secret".to_string()).is_handled());
+
+        struct Double;
+        impl Receive<u8> for Double {
+            type Output = u8;
+
+            fn send(&mut self, event: u8) -> ReceiverResult<u8, Self::Output> {
+                ReceiverResult::Continue(event * 2)
+            }
+        }
+
+        let double = RcLinker::new(Double);
+        bus.intercept_from_receiver::<u8>(double.linked());
+        assert_eq!(bus.publish(3u8), Published::Handled(6));
+        drop(double);
+        assert_eq!(bus.publish(3u8), Published::Unhandled(3));
+
+        let seen = ArcLinker::new(Seen(Vec::new()));
+        let bus = Arc::new(Mutex::new(SendEventBus::new()));
+        bus.lock().unwrap().subscribe::<u64>(seen.linked()).unwrap();
+
+        let workers: Vec<_> = (0..4u64)
+            .map(|i| {
+                let bus = bus.clone();
+                thread::spawn(move || bus.lock().unwrap().publish(i).is_handled())
+            })
+            .collect();
+        assert!(workers.into_iter().all(|worker| worker.join().unwrap()));
+
+        let mut seen_events = seen.lock().as_ref().unwrap().0.clone();
+        seen_events.sort();
+        assert_eq!(seen_events, vec![0, 1, 2, 3]);
+
+        drop(seen);
+        assert_eq!(bus.lock().unwrap().publish(4u64), Published::Unhandled(4));
+    }
+
+    #[test]
+    fn topic_router() {
+        use crate::{
+            event_bus::Published,
+            topic_router::{topic_error::TopicError, TopicRouter},
+        };
+
+        struct Tagged(&'static str, Rc<RefCell<Vec<(&'static str, i32)>>>);
+        impl View<i32> for Tagged {
+            fn view(&mut self, event: &i32) -> Option<crate::view::DeleteView> {
+                self.1.borrow_mut().push((self.0, *event));
+                None
+            }
+        }
+
+        struct Armour;
+        impl Receive<i32> for Armour {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                match event {
+                    0 => ReceiverResult::Stop,
+                    event => ReceiverResult::Continue(event - 1),
+                }
+            }
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut topics = TopicRouter::new();
+        topics
+            .subscribe("player.#", Tagged("player.#", log.clone()))
+            .unwrap();
+        topics
+            .subscribe("player.*.damage", Tagged("player.*.damage", log.clone()))
+            .unwrap();
+        topics
+            .subscribe("player.42.damage", Tagged("player.42.damage", log.clone()))
+            .unwrap();
+        let armour = RcLinker::new(Armour);
+        topics
+            .intercept_from_receiver("player.42.*", armour.linked())
+            .unwrap();
+
+        assert_eq!(
+            topics.subscribe("player.#.damage", Tagged("bad", log.clone())),
+            Err(TopicError::MisplacedWildcard)
+        );
+
+        assert_eq!(topics.publish("player.42.damage", 5), Published::Handled(4));
+        assert_eq!(
+            log.take(),
+            vec![
+                ("player.42.damage", 5),
+                ("player.*.damage", 4),
+                ("player.#", 4)
+            ]
+        );
+
+        assert_eq!(topics.publish("player.7.damage", 3), Published::Handled(3));
+        assert_eq!(log.take(), vec![("player.*.damage", 3), ("player.#", 3)]);
+
+        assert_eq!(topics.publish("player", 1), Published::Handled(1));
+        assert_eq!(log.take(), vec![("player.#", 1)]);
+
+        assert_eq!(topics.publish("player.42.heal", 0), Published::Stopped);
+        assert_eq!(topics.publish("enemy.1.damage", 2), Published::Unhandled(2));
+        assert!(log.take().is_empty());
+
+        drop(armour);
+        assert_eq!(topics.collect_garbage(), 1);
+        assert!(topics.unsubscribe("player.#", 0).is_some());
+        assert_eq!(topics.publish("player.42.heal", 0), Published::Unhandled(0));
+        assert_eq!(topics.publish("player.1.damage", 0), Published::Handled(0));
+        assert_eq!(log.take(), vec![("player.*.damage", 0)]);
+
+        let watcher = RcLinker::new(Tagged("enemy.#", log.clone()));
+        topics.subscribe("enemy.#", watcher.linked()).unwrap();
+        assert_eq!(topics.publish("enemy.1.damage", 2), Published::Handled(2));
+        drop(watcher);
+        assert_eq!(topics.publish("enemy.1.damage", 2), Published::Unhandled(2));
+        assert_eq!(log.take(), vec![("enemy.#", 2)]);
+    }
+
+    #[test]
+    fn event_queue() {
+        use crate::event_queue::{EventQueue, Overflow, Queued};
+
+        #[derive(Default)]
+        struct Total(Vec<i32>);
+        impl Receive<i32> for Total {
+            type Output = usize;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if event < 0 {
+                    return ReceiverResult::Stop;
+                }
+                self.0.push(event);
+                ReceiverResult::Continue(self.0.len())
+            }
+        }
+
+        let mut router = Router::new(EventQueue::new());
+        assert_eq!(router.send(1).unwrap_continue(), Queued::Accepted);
+        router.send(-1);
+        router.send(2);
+
+        let mut total = Total::default();
+        let results = router.get_receiver_mut().flush(&mut total);
+        assert_eq!(
+            results,
+            vec![
+                ReceiverResult::Continue(1),
+                ReceiverResult::Stop,
+                ReceiverResult::Continue(2)
+            ]
+        );
+        assert!(router.get_receiver().is_empty());
+
+        let mut queue = EventQueue::with_priority(|event: &i32| (*event % 10) as i64)
+            .bounded(3, Overflow::DropLast);
+        queue.send(11);
+        queue.send(5);
+        queue.send(21);
+        assert_eq!(queue.send(9).unwrap_continue(), Queued::Displaced(21));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![9, 5, 11]);
+        assert_eq!(queue.send(30).unwrap_continue(), Queued::Rejected(30));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![9, 5, 11]);
+
+        let mut queue =
+            EventQueue::with_priority(|event: &i32| *event as i64).bounded(2, Overflow::DropFirst);
+        queue.send(1);
+        queue.send(2);
+        assert_eq!(queue.send(5).unwrap_continue(), Queued::Rejected(5));
+        assert_eq!(queue.send(0).unwrap_continue(), Queued::Displaced(2));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 0]);
+
+        let mut queue = EventQueue::new().bounded(2, Overflow::Reject);
+        queue.send(1);
+        queue.send(2);
+        assert_eq!(queue.send(3).unwrap_continue(), Queued::Rejected(3));
+
+        let mut queue = queue.bounded(2, Overflow::DropFirst);
+        assert_eq!(queue.send(3).unwrap_continue(), Queued::Displaced(1));
+        let mut total = Total::default();
+        queue.flush(&mut total);
+        assert_eq!(total.0, vec![2, 3]);
+    }
+
+    #[test]
+    fn channel_pumps() {
+        use std::sync::mpsc;
+
+        use crate::{
+            channel::{self, ChannelSender, RouterPump},
+            send_router::SendRouter,
+        };
+
+        #[derive(Default)]
+        struct Total(i32);
+        impl Receive<i32> for Total {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if event == 0 {
+                    return ReceiverResult::Delete(event);
+                }
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        let (events, pumped) = channel::channel();
+        let (replies, outputs) = mpsc::channel();
+        let pump =
+            RouterPump::spawn_with_replies(SendRouter::new(Total::default()), pumped, replies);
+
+        let mut router = Router::new(events);
+        router.send(1).unwrap_continue();
+        router.send(2).unwrap_continue();
+        assert_eq!(outputs.recv().unwrap(), 1);
+        assert_eq!(outputs.recv().unwrap(), 3);
+
+        router.send(3).unwrap_continue();
+        let router_total = pump.shutdown().unwrap();
+        assert_eq!(router_total.get_receiver().0, 6);
+
+        assert_eq!(router.send(4), ReceiverResult::Delete(4));
+        assert!(!router.is_alive());
+
+        let (mut sender, pumped) = channel::sync_channel(1);
+        let pump = RouterPump::spawn(Total::default(), pumped);
+        sender.send(5).unwrap_continue();
+        sender.send(0).unwrap_continue();
+        assert_eq!(pump.join().unwrap().0, 5);
+        assert_eq!(sender.send(6), ReceiverResult::Delete(6));
+
+        // shutting down doesn't wait for a sender that keeps sending
+        let (mut sender, pumped) = channel::sync_channel(4);
+        let pump = RouterPump::spawn(Total::default(), pumped);
+        let flood = thread::spawn(move || while sender.send(1).is_continue() {});
+        pump.shutdown().unwrap();
+        flood.join().unwrap();
+
+        // a dropped pump stops even though its sender is still alive
+        let (mut sender, pumped) = channel::channel();
+        let pump = RouterPump::spawn(Total::default(), pumped);
+        sender.send(1).unwrap_continue();
+        drop(pump);
+        assert_eq!(sender.send(2), ReceiverResult::Delete(2));
+
+        let (events, forwarded) = mpsc::channel();
+        ChannelSender::new(events).send(3).unwrap_continue();
+        assert_eq!(forwarded.recv().unwrap(), 3);
+    }
+
+    #[test]
+    fn actors() {
+        use std::sync::mpsc;
+
+        use crate::{actor::Actor, send_router::SendRouter};
+
+        #[derive(Default)]
+        struct Total(i32);
+        impl Receive<i32> for Total {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if event == 0 {
+                    return ReceiverResult::Delete(event);
+                }
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        let actor = Actor::spawn(SendRouter::new(Total::default()), 4);
+        let handles: Vec<_> = (1..=4)
+            .map(|i| {
+                let mut handle = actor.handle();
+                thread::spawn(move || handle.send(i).unwrap_continue().wait().unwrap())
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap().is_continue());
+        }
+
+        let mut handle = actor.handle();
+        let reply = handle.send(5).unwrap_continue();
+        assert_eq!(reply.wait(), Ok(ReceiverResult::Continue(15)));
+
+        let router = actor.stop().unwrap();
+        assert_eq!(router.get_receiver().0, 15);
+        assert!(!handle.is_alive());
+        assert_eq!(handle.send(6).unwrap_delete(), 6);
+
+        let actor = Actor::spawn(Total::default(), 1);
+        let mut handle = actor.handle();
+        let reply = handle.send(0).unwrap_continue();
+        assert_eq!(reply.wait(), Ok(ReceiverResult::Delete(0)));
+        assert_eq!(handle.send(1).unwrap_delete(), 1);
+        assert!(!actor.is_running());
+
+        let actor = Actor::spawn(Total::default(), 1);
+        let mut handle = actor.handle();
+        drop(actor);
+        assert_eq!(handle.send(2).unwrap_delete(), 2);
+
+        // only handles the events it is let through
+        struct Gated(mpsc::Receiver<()>, Total);
+        impl Receive<i32> for Gated {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0.recv().unwrap();
+                self.1.send(event)
+            }
+        }
+
+        let (gate, gated) = mpsc::channel();
+        let actor = Actor::spawn(Gated(gated, Total::default()), 4);
+        let mut handle = actor.handle();
+        let deleting = handle.send(0).unwrap_continue();
+        let queued = handle.send(1).unwrap_continue();
+        gate.send(()).unwrap();
+        assert_eq!(deleting.wait(), Ok(ReceiverResult::Delete(0)));
+        assert_eq!(queued.wait(), Ok(ReceiverResult::Delete(1)));
+        drop(actor);
+
+        // a handle that keeps sending doesn't keep a dropped actor alive
+        let actor = Actor::spawn(Total::default(), 1);
+        let mut handle = actor.handle();
+        let flood = thread::spawn(move || while handle.send(1).is_continue() {});
+        drop(actor);
+        flood.join().unwrap();
+
+        // fire and forget sends are still handled
+        let actor = Actor::spawn(Total::default(), 64);
+        let mut handle = actor.handle();
+        for _ in 0..50 {
+            let _ = handle.send(1);
+        }
+        assert_eq!(actor.stop().unwrap().0, 50);
+    }
+
+    #[test]
+    fn replies() {
+        use std::{
+            future::Future,
+            sync::mpsc,
+            task::{Context, Poll, Wake, Waker},
+            time::Duration,
+        };
+
+        use crate::reply::{reply, reply_error::ReplyError, Deferred};
+
+        struct Slow(i32);
+        impl Receive<i32> for Slow {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                thread::sleep(Duration::from_millis(50));
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        let mut router = Router::new(Deferred::new(Slow(0)));
+        let mut first = router.send(1).unwrap_continue();
+        assert_eq!(first.wait_timeout(Duration::ZERO), Err(ReplyError::Pending));
+        assert_eq!(first.wait(), Ok(ReceiverResult::Continue(1)));
+
+        // the receiver is locked until the reply is dropped, so the event is cancelled before it runs
+        let shared = router.get_receiver().get_receiver().clone();
+        let lock = shared.lock().unwrap();
+        drop(router.send(100).unwrap_continue());
+        router.send(1000).unwrap_continue().detach();
+        drop(lock);
+
+        while shared.lock().unwrap().0 == 1 {
+            thread::yield_now();
+        }
+        let last = router.send(10).unwrap_continue();
+        assert_eq!(last.wait(), Ok(ReceiverResult::Continue(1011)));
+
+        // every event is handled by the same worker in the order it was sent
+        struct Ordered(Vec<i32>);
+        impl Receive<i32> for Ordered {
+            type Output = usize;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                self.0.push(event);
+                ReceiverResult::Continue(self.0.len())
+            }
+        }
+
+        let mut ordered = Deferred::new(Ordered(Vec::new()));
+        let replies: Vec<_> = (0..100)
+            .map(|i| ordered.send(i).unwrap_continue())
+            .collect();
+        for (i, reply) in replies.into_iter().enumerate() {
+            assert_eq!(reply.wait(), Ok(ReceiverResult::Continue(i + 1)));
+        }
+        assert_eq!(
+            ordered.get_receiver().lock().unwrap().0,
+            (0..100).collect::<Vec<_>>()
+        );
+
+        let (responder, pending) = reply::<i32>();
+        drop(responder);
+        assert_eq!(pending.wait(), Err(ReplyError::Dropped));
+
+        let (responder, mut taken) = reply::<i32>();
+        responder.send(3).unwrap();
+        assert_eq!(taken.try_get(), Ok(3));
+        assert_eq!(taken.try_get(), Err(ReplyError::Taken));
+
+        // once the receiver deletes itself the deferred receiver dies and hands events back
+        struct Once;
+        impl Receive<i32> for Once {
+            type Output = ();
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                ReceiverResult::Delete(event)
+            }
+        }
+
+        let mut once = Deferred::new(Once);
+        let first = once.send(1).unwrap_continue();
+        assert_eq!(first.wait(), Ok(ReceiverResult::Delete(1)));
+        assert!(!once.is_alive());
+        assert!(matches!(once.send(2), ReceiverResult::Delete(2)));
+
+        let (responder, cancelled) = reply::<i32>();
+        drop(cancelled);
+        assert!(responder.is_cancelled());
+        assert_eq!(responder.send(1), Err(1));
+
+        struct Notify(mpsc::Sender<()>);
+        impl Wake for Notify {
+            fn wake(self: std::sync::Arc<Self>) {
+                let _ = self.0.send(());
+            }
+        }
+
+        let (woken, wakes) = mpsc::channel();
+        let waker = Waker::from(std::sync::Arc::new(Notify(woken)));
+        let mut context = Context::from_waker(&waker);
+
+        let (responder, mut reply) = reply::<i32>();
+        let mut future = std::pin::Pin::new(&mut reply);
+        assert_eq!(future.as_mut().poll(&mut context), Poll::Pending);
+        thread::spawn(move || responder.send(7));
+        wakes.recv().unwrap();
+        assert_eq!(future.poll(&mut context), Poll::Ready(Ok(7)));
+    }
+
+    #[test]
+    fn record_and_replay() {
+        use std::time::Duration;
+
+        use crate::{
+            recorder::{Outcome, Record, Recorded, Recorder},
+            replayer::{replay_error::ReplayError, Replayer, VirtualClock},
+        };
+
+        #[derive(Default)]
+        struct Health(i32);
+        impl Receive<i32> for Health {
+            type Output = i32;
+
+            fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+                if self.0 + event < 0 {
+                    return ReceiverResult::Stop;
+                }
+                self.0 += event;
+                ReceiverResult::Continue(self.0)
+            }
+        }
+
+        let viewer = RcLinker::new(Recorder::new());
+        let intercept = RcLinker::new(Recorder::new());
+        let mut router = Router::new_exposed(Health::default());
+        router
+            .get_receiver_mut()
+            .box_and_add_viewer(viewer.linked())
+            .unwrap();
+        router.intercept_from_receiver(intercept.linked());
+
+        let mut recorder = Recorder::new();
+        for event in [5, -2, -10, 3] {
+            recorder.record_send(&mut router, event);
+        }
+
+        let viewed: Vec<i32> = viewer
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .records()
+            .iter()
+            .map(|record| record.event)
+            .collect();
+        assert_eq!(viewed, vec![5, -2, -10, 3]);
+        assert_eq!(intercept.borrow().as_ref().unwrap().records().len(), 4);
+
+        let records = recorder.take_records();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| (record.sequence, record.outcome))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, Some(Outcome::Continue)),
+                (1, Some(Outcome::Continue)),
+                (2, Some(Outcome::Stop)),
+                (3, Some(Outcome::Continue)),
+            ]
+        );
+
+        let mut replayed = Health::default();
+        assert_eq!(Replayer::new(records.clone()).replay(&mut replayed), Ok(4));
+        assert_eq!(replayed.0, 6);
+
+        let timed: Vec<_> = records
+            .into_iter()
+            .enumerate()
+            .map(|(i, record)| Record {
+                elapsed: Duration::from_secs(i as u64 * 60),
+                ..record
+            })
+            .collect();
+        let mut clock = VirtualClock::new();
+        let mut replayed = Health::default();
+        assert_eq!(
+            Replayer::new(timed.clone()).replay_timed(&mut replayed, &mut clock),
+            Ok(4)
+        );
+        assert_eq!(clock.now(), Duration::from_secs(180));
+
+        let mut wounded = Health(-20);
+        assert_eq!(
+            Replayer::new(timed).replay(&mut wounded),
+            Err(ReplayError {
+                sequence: 0,
+                expected: Outcome::Continue,
+                actual: Outcome::Stop
+            })
+        );
+
+        // recording around the target router keeps the outcomes
+        let mut recorded = Router::new(Recorded::new(Router::new(Health::default())));
+        for event in [4, -6, 1] {
+            recorded.send(event);
+        }
+        let records = recorded
+            .get_receiver_mut()
+            .get_recorder_mut()
+            .take_records();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.outcome)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(Outcome::Continue),
+                Some(Outcome::Stop),
+                Some(Outcome::Continue)
+            ]
+        );
+
+        let mut healed = Router::new(Health(10));
+        assert_eq!(
+            Replayer::new(records).replay(&mut healed),
+            Err(ReplayError {
+                sequence: 1,
+                expected: Outcome::Stop,
+                actual: Outcome::Continue
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn event_log() {
+        use serde::{Deserialize, Serialize};
+
+        use crate::{
+            event_log::{
+                event_log_error::EventLogError, Encoding, EventLogReader, EventLogWriter, MAGIC,
+            },
+            recorder::{Outcome, Recorder},
+            replayer::Replayer,
+        };
+
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        enum Input {
+            Move { x: i32, y: i32 },
+            Jump,
+        }
+
+        struct Position(i32, i32);
+        impl Receive<Input> for Position {
+            type Output = ();
+
+            fn send(&mut self, event: Input) -> ReceiverResult<Input, Self::Output> {
+                match event {
+                    Input::Move { x, y } => {
+                        self.0 += x;
+                        self.1 += y;
+                        ReceiverResult::Continue(())
+                    }
+                    Input::Jump => ReceiverResult::Stop,
+                }
+            }
+        }
+
+        let events = [
+            Input::Move { x: 1, y: 2 },
+            Input::Jump,
+            Input::Move { x: -3, y: 0 },
+        ];
+        for encoding in [Encoding::Binary, Encoding::JsonLines] {
+            let writer = EventLogWriter::new(Vec::new(), encoding, "input", 2).unwrap();
+            let mut recorder = Recorder::with_sink(writer);
+            let mut position = Position(0, 0);
+            for event in events.clone() {
+                recorder.record_send(&mut position, event);
+            }
+            let log = recorder.into_sink().into_inner();
+            assert_eq!(log.starts_with(&MAGIC), encoding == Encoding::Binary);
+
+            let reader =
+                EventLogReader::<_, Input>::open_checked(log.as_slice(), encoding, "input", 2)
+                    .unwrap();
+            assert_eq!(reader.header().event_type, "input");
+            let records: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+            assert_eq!(
+                records
+                    .iter()
+                    .map(|record| record.event.clone())
+                    .collect::<Vec<_>>(),
+                events
+            );
+            assert_eq!(records[1].outcome, Some(Outcome::Stop));
+
+            let mut replayed = Position(0, 0);
+            assert_eq!(Replayer::new(records).replay(&mut replayed), Ok(3));
+            assert_eq!((replayed.0, replayed.1), (position.0, position.1));
+
+            assert!(matches!(
+                EventLogReader::<_, Input>::open_checked(log.as_slice(), encoding, "input", 3),
+                Err(EventLogError::SchemaMismatch {
+                    expected: 3,
+                    found: 2
+                })
+            ));
+            assert!(matches!(
+                EventLogReader::<_, u8>::open_checked(log.as_slice(), encoding, "byte", 2),
+                Err(EventLogError::TypeMismatch { .. })
+            ));
+
+            let truncated = &log[..log.len() - 2];
+            let mut reader = EventLogReader::<_, Input>::open(truncated, encoding).unwrap();
+            assert!(reader.nth(2).unwrap().is_err());
+        }
+
+        // a corrupt length fails on the missing bytes instead of allocating them
+        let mut corrupt = EventLogWriter::<_, Input>::new(Vec::new(), Encoding::Binary, "input", 1)
+            .unwrap()
+            .into_inner();
+        corrupt.extend_from_slice(&u32::MAX.to_le_bytes());
+        corrupt.extend_from_slice(b"short");
+        let mut reader =
+            EventLogReader::<_, Input>::open(corrupt.as_slice(), Encoding::Binary).unwrap();
+        assert!(matches!(
+            reader.read(),
+            Err(EventLogError::Io(error)) if error.kind() == std::io::ErrorKind::UnexpectedEof
+        ));
+
+        assert!(matches!(
+            EventLogReader::<_, Input>::open(&b"JUNK"[..], Encoding::Binary),
+            Err(EventLogError::BadMagic)
+        ));
+    }
+
+    #[test]
+    fn linker_equality() {
+        use crate::arc_linker::ArcLinker;
+
+        let a = RcLinker::new(1);
+        let b = RcLinker::new(1);
+        assert!(a == b);
+        assert!(a != RcLinker::new(2));
+        let borrowed = b.borrow_mut();
+        assert!(a != b);
+        drop(borrowed);
+
+        // comparing in opposite orders on two threads doesn't deadlock
+        let a = ArcLinker::new(5u64);
+        let b = ArcLinker::new(5u64);
+        thread::scope(|scope| {
+            for (this, other) in [(&a, &b), (&b, &a)] {
+                scope.spawn(move || {
+                    for _ in 0..10_000 {
+                        let _ = this == other;
+                    }
+                });
+            }
+        });
+        assert!(a == b);
+    }
 }
@@ -0,0 +1,326 @@
+pub mod link_error;
+pub mod link_policy;
+pub mod link_scope;
+pub mod linked;
+pub mod lock_policy;
+pub mod revoke_token;
+
+use std::{
+    collections::BTreeMap,
+    ops::Deref,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use crate::link_id::LinkId;
+
+use self::{
+    link_error::LinkError,
+    link_policy::{DropHookPolicy, LinkPolicy, UnlinkedHookPolicy},
+    linked::Linked,
+    lock_policy::{LockPolicy, LockWait, PoisonPolicy},
+    revoke_token::RevokeToken,
+};
+
+static NEXT_THREAD_TOKEN: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    static THREAD_TOKEN: usize = NEXT_THREAD_TOKEN.fetch_add(1, Ordering::Relaxed);
+}
+
+fn thread_token() -> usize {
+    THREAD_TOKEN.with(|token| *token)
+}
+
+struct Dispatching<'a> {
+    dispatcher: &'a AtomicUsize,
+    previous: usize,
+}
+
+impl Drop for Dispatching<'_> {
+    fn drop(&mut self) {
+        self.dispatcher.store(self.previous, Ordering::Release);
+    }
+}
+
+struct Handle {
+    revoked: AtomicBool,
+    instances: AtomicUsize,
+}
+
+struct Link<R, P: LinkPolicy> {
+    receiver: P::Lock<Option<R>>,
+    policy: LockPolicy,
+    alive: AtomicBool,
+    dispatcher: AtomicUsize,
+    pending_drop: AtomicBool,
+    links: AtomicUsize,
+    next_id: AtomicUsize,
+    handles: P::Lock<BTreeMap<LinkId, P::Shared<Handle>>>,
+    on_drop: P::Lock<Option<P::DropHook<R>>>,
+    on_unlinked: P::Lock<Option<P::UnlinkedHook>>,
+}
+
+impl<R, P: LinkPolicy> Link<R, P> {
+    fn new(receiver: R, policy: LockPolicy) -> Self {
+        Self {
+            receiver: P::new_lock(Some(receiver)),
+            policy,
+            alive: AtomicBool::new(true),
+            dispatcher: AtomicUsize::new(0),
+            pending_drop: AtomicBool::new(false),
+            links: AtomicUsize::new(0),
+            next_id: AtomicUsize::new(0),
+            handles: P::new_lock(BTreeMap::new()),
+            on_drop: P::new_lock(None),
+            on_unlinked: P::new_lock(None),
+        }
+    }
+
+    fn dispatch<T>(
+        &self,
+        mut guard: P::Guard<'_, Option<R>>,
+        f: impl FnOnce(Option<&mut R>) -> T,
+    ) -> T {
+        let output = {
+            let _dispatching = Dispatching {
+                dispatcher: &self.dispatcher,
+                previous: self.dispatcher.swap(thread_token(), Ordering::AcqRel),
+            };
+            f(guard.as_mut())
+        };
+
+        if self.pending_drop.swap(false, Ordering::AcqRel) {
+            let receiver = guard.take();
+            drop(guard);
+            self.release(receiver);
+        }
+
+        output
+    }
+
+    fn release(&self, receiver: Option<R>) {
+        if let Some(receiver) = receiver {
+            let hook = P::lock(&self.on_drop).take();
+            if let Some(hook) = hook {
+                P::call_drop_hook(hook, receiver);
+            }
+        }
+    }
+
+    fn link(&self) -> (LinkId, P::Shared<Handle>) {
+        let id = LinkId(self.next_id.fetch_add(1, Ordering::Relaxed));
+
+        let handle = P::share(Handle {
+            revoked: AtomicBool::new(false),
+            instances: AtomicUsize::new(1),
+        });
+        P::lock(&self.handles).insert(id, handle.clone());
+        self.links.fetch_add(1, Ordering::AcqRel);
+
+        (id, handle)
+    }
+
+    fn relink(&self, handle: &Handle) {
+        handle.instances.fetch_add(1, Ordering::AcqRel);
+        self.links.fetch_add(1, Ordering::AcqRel);
+    }
+
+    fn unlink(&self, id: LinkId, handle: &Handle) {
+        if handle.instances.fetch_sub(1, Ordering::AcqRel) == 1 {
+            P::lock(&self.handles).remove(&id);
+        }
+
+        if self.links.fetch_sub(1, Ordering::AcqRel) == 1 {
+            // the hook is taken out while it runs so that it may freely use the linker
+            let hook = P::lock(&self.on_unlinked).take();
+            if let Some(mut hook) = hook {
+                P::call_unlinked_hook(&mut hook);
+                let mut slot = P::lock(&self.on_unlinked);
+                if slot.is_none() {
+                    *slot = Some(hook);
+                }
+            }
+        }
+    }
+}
+
+pub struct Linker<R, P: LinkPolicy> {
+    link: P::Shared<Link<R, P>>,
+}
+
+impl<R, P: LinkPolicy> Linker<R, P> {
+    pub fn new(receiver: R) -> Self {
+        Self::with_policy(receiver, LockPolicy::default())
+    }
+
+    pub fn with_policy(receiver: R, policy: LockPolicy) -> Self {
+        Self {
+            link: P::share(Link::new(receiver, policy)),
+        }
+    }
+
+    pub fn policy(&self) -> LockPolicy {
+        self.link.policy
+    }
+
+    pub fn get_receiver(&self) -> &P::Lock<Option<R>> {
+        &self.link.receiver
+    }
+
+    pub fn acquire(&self) -> Result<P::Guard<'_, Option<R>>, LinkError> {
+        P::try_lock(&self.link.receiver, self.link.policy)
+    }
+
+    pub fn linked(&self) -> Linked<R, P> {
+        self.linked_revocable().0
+    }
+
+    pub fn linked_revocable(&self) -> (Linked<R, P>, RevokeToken<P>) {
+        let (id, handle) = self.link.link();
+        let linked = Linked {
+            link: self.link.clone(),
+            id,
+            handle: handle.clone(),
+            policy: self.link.policy,
+        };
+        (linked, RevokeToken { id, handle })
+    }
+
+    pub fn handles(&self) -> Vec<LinkId> {
+        P::lock(&self.link.handles).keys().copied().collect()
+    }
+
+    pub fn is_revoked(&self, id: LinkId) -> Option<bool> {
+        P::lock(&self.link.handles)
+            .get(&id)
+            .map(|handle| handle.revoked.load(Ordering::Acquire))
+    }
+
+    pub fn revoke(&self, id: LinkId) -> bool {
+        match P::lock(&self.link.handles).get(&id) {
+            Some(handle) => {
+                handle.revoked.store(true, Ordering::Release);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.link.alive.load(Ordering::Acquire)
+    }
+
+    pub fn link_count(&self) -> usize {
+        self.link.links.load(Ordering::Acquire)
+    }
+
+    pub fn on_drop<F>(&self, hook: F)
+    where
+        F: FnOnce(R),
+        P: DropHookPolicy<R, F>,
+    {
+        *P::lock(&self.link.on_drop) = Some(P::drop_hook(hook));
+    }
+
+    pub fn on_unlinked<F>(&self, hook: F)
+    where
+        F: FnMut(),
+        P: UnlinkedHookPolicy<F>,
+    {
+        *P::lock(&self.link.on_unlinked) = Some(P::unlinked_hook(hook));
+    }
+}
+
+impl<R, P: LinkPolicy> Clone for Linker<R, P> {
+    fn clone(&self) -> Self {
+        Self {
+            link: self.link.clone(),
+        }
+    }
+}
+
+impl<R, P: LinkPolicy> Deref for Linker<R, P> {
+    type Target = P::Lock<Option<R>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.link.receiver
+    }
+}
+
+impl<R, P: LinkPolicy> Drop for Linker<R, P> {
+    fn drop(&mut self) {
+        self.link.alive.store(false, Ordering::Release);
+
+        // the receiver is being dispatched to further up this thread's stack, so it is still borrowed, the
+        // dispatching handle takes it out once the receiver returns
+        if self.link.dispatcher.load(Ordering::Acquire) == thread_token() {
+            self.link.pending_drop.store(true, Ordering::Release);
+            return;
+        }
+
+        let receiver = P::lock(&self.link.receiver).take();
+        self.link.release(receiver);
+    }
+}
+
+/// a receiver that is locked or borrowed elsewhere compares unequal, so comparing two linkers in opposite orders on
+/// two threads, or from inside a dispatch, can't deadlock or panic
+impl<R: PartialEq, P: LinkPolicy> PartialEq for Linker<R, P> {
+    fn eq(&self, other: &Self) -> bool {
+        if P::ptr_eq(&self.link, &other.link) {
+            return true;
+        }
+
+        let policy = LockPolicy::new(PoisonPolicy::Recover, LockWait::Try);
+        match (
+            P::try_lock(&self.link.receiver, policy),
+            P::try_lock(&other.link.receiver, policy),
+        ) {
+            (Ok(this), Ok(other)) => *this == *other,
+            _ => false,
+        }
+    }
+}
+
+impl<R: Eq, P: LinkPolicy> Eq for Linker<R, P> {}
+
+impl<R: Default, P: LinkPolicy> Default for Linker<R, P> {
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
+
+impl<R, P: LinkPolicy> std::fmt::Debug for Linker<R, P>
+where
+    P::Lock<Option<R>>: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{links: {}, receiver: {:?}}}",
+            self.link_count(),
+            self.link.receiver
+        )
+    }
+}
+
+impl<R: std::fmt::Display, P: LinkPolicy> std::fmt::Display for Linker<R, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display_receiver::<R, P>(&self.link.receiver, f)
+    }
+}
+
+fn display_receiver<R: std::fmt::Display, P: LinkPolicy>(
+    receiver: &P::Lock<Option<R>>,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let policy = LockPolicy::new(PoisonPolicy::Propagate, LockWait::Try);
+    match P::try_lock(receiver, policy) {
+        Ok(guard) => match guard.as_ref() {
+            Some(value) => value.fmt(f),
+            None => write!(f, "<deleted>"),
+        },
+        Err(LinkError::Busy(())) => write!(f, "<locked>"),
+        Err(LinkError::Poisoned(())) => write!(f, "<poisoned>"),
+    }
+}
@@ -0,0 +1,35 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinkError<E = ()> {
+    /// the lock was held elsewhere for longer than the [`LockWait`][`super::lock_policy::LockWait`] allowed
+    Busy(E),
+    /// a receiver panicked while holding the lock and the [`PoisonPolicy`][`super::lock_policy::PoisonPolicy`]
+    /// asked for it to be propagated
+    Poisoned(E),
+}
+
+impl<E> LinkError<E> {
+    pub fn is_busy(&self) -> bool {
+        matches!(self, LinkError::Busy(_))
+    }
+    pub fn is_poisoned(&self) -> bool {
+        matches!(self, LinkError::Poisoned(_))
+    }
+
+    pub fn into_event(self) -> E {
+        match self {
+            LinkError::Busy(e) | LinkError::Poisoned(e) => e,
+        }
+    }
+}
+
+impl<E> std::fmt::Display for LinkError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkError::Busy(_) => write!(f, "linked receiver is busy"),
+            LinkError::Poisoned(_) => write!(f, "linked receiver is poisoned"),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug> std::error::Error for LinkError<E> {}
@@ -0,0 +1,288 @@
+use std::{
+    any::Any,
+    cell::{RefCell, RefMut},
+    ops::{Deref, DerefMut},
+    sync::{
+        Arc, LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockWriteGuard, TryLockError,
+        TryLockResult,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use compact_rc::Rc;
+
+use super::{
+    link_error::LinkError,
+    lock_policy::{LockPolicy, LockWait, PoisonPolicy},
+};
+
+pub trait LinkPolicy: Sized {
+    type Shared<T>: Deref<Target = T> + Clone;
+    type Lock<T>;
+    type Guard<'a, T: 'a>: DerefMut<Target = T>;
+    type DropHook<R>;
+    type UnlinkedHook;
+    /// a type-erased value kept alive by a [`LinkScope`][`super::link_scope::LinkScope`]
+    type Owned;
+
+    fn share<T>(value: T) -> Self::Shared<T>;
+    fn ptr_eq<T>(this: &Self::Shared<T>, other: &Self::Shared<T>) -> bool;
+
+    fn new_lock<T>(value: T) -> Self::Lock<T>;
+    fn lock<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T>;
+    fn try_lock<T>(
+        lock: &Self::Lock<T>,
+        policy: LockPolicy,
+    ) -> Result<Self::Guard<'_, T>, LinkError>;
+
+    fn call_drop_hook<R>(hook: Self::DropHook<R>, receiver: R);
+    fn call_unlinked_hook(hook: &mut Self::UnlinkedHook);
+}
+
+pub trait DropHookPolicy<R, F>: LinkPolicy {
+    fn drop_hook(hook: F) -> Self::DropHook<R>;
+}
+
+pub trait UnlinkedHookPolicy<F>: LinkPolicy {
+    fn unlinked_hook(hook: F) -> Self::UnlinkedHook;
+}
+
+pub trait OwnedPolicy<T>: LinkPolicy {
+    fn owned(value: T) -> Self::Owned;
+}
+
+/// the longest sleep between two attempts of a [`LockWait::Timeout`]
+const MAX_BACKOFF: Duration = Duration::from_millis(2);
+
+fn acquire<G>(
+    policy: LockPolicy,
+    lock: impl FnOnce() -> LockResult<G>,
+    try_lock: impl Fn() -> TryLockResult<G>,
+    clear_poison: impl FnOnce(),
+) -> Result<G, LinkError> {
+    let result = match policy.wait {
+        LockWait::Block => lock().map_err(TryLockError::Poisoned),
+        LockWait::Try => try_lock(),
+        LockWait::Timeout(timeout) => {
+            let deadline = Instant::now() + timeout;
+            // std locks have no timed acquire, so the lock is retried with a backoff instead of spinning a core
+            let mut backoff = Duration::from_micros(10);
+            loop {
+                match try_lock() {
+                    Err(TryLockError::WouldBlock) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            break Err(TryLockError::WouldBlock);
+                        }
+                        thread::sleep(backoff.min(deadline - now));
+                        backoff = (backoff * 2).min(MAX_BACKOFF);
+                    }
+                    result => break result,
+                }
+            }
+        }
+    };
+
+    match result {
+        Ok(guard) => Ok(guard),
+        Err(TryLockError::WouldBlock) => Err(LinkError::Busy(())),
+        Err(TryLockError::Poisoned(poisoned)) => match policy.poison {
+            PoisonPolicy::Recover => {
+                clear_poison();
+                Ok(poisoned.into_inner())
+            }
+            PoisonPolicy::Delete | PoisonPolicy::Propagate => Err(LinkError::Poisoned(())),
+        },
+    }
+}
+
+// Rc + RefCell
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RcRefCell;
+
+impl LinkPolicy for RcRefCell {
+    type Shared<T> = Rc<T>;
+    type Lock<T> = RefCell<T>;
+    type Guard<'a, T: 'a> = RefMut<'a, T>;
+    type DropHook<R> = Box<dyn FnOnce(R)>;
+    type UnlinkedHook = Box<dyn FnMut()>;
+    type Owned = Box<dyn Any>;
+
+    fn share<T>(value: T) -> Self::Shared<T> {
+        Rc::new(value)
+    }
+    fn ptr_eq<T>(this: &Self::Shared<T>, other: &Self::Shared<T>) -> bool {
+        Rc::ptr_eq(this, other)
+    }
+
+    fn new_lock<T>(value: T) -> Self::Lock<T> {
+        RefCell::new(value)
+    }
+    fn lock<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T> {
+        lock.borrow_mut()
+    }
+    fn try_lock<T>(
+        lock: &Self::Lock<T>,
+        policy: LockPolicy,
+    ) -> Result<Self::Guard<'_, T>, LinkError> {
+        match policy.wait {
+            LockWait::Block => Ok(lock.borrow_mut()),
+            // nothing else can release the borrow while this thread waits, so there is no point in waiting
+            LockWait::Try | LockWait::Timeout(_) => {
+                lock.try_borrow_mut().map_err(|_| LinkError::Busy(()))
+            }
+        }
+    }
+
+    fn call_drop_hook<R>(hook: Self::DropHook<R>, receiver: R) {
+        hook(receiver)
+    }
+    fn call_unlinked_hook(hook: &mut Self::UnlinkedHook) {
+        hook()
+    }
+}
+
+impl<R, F: FnOnce(R) + 'static> DropHookPolicy<R, F> for RcRefCell {
+    fn drop_hook(hook: F) -> Self::DropHook<R> {
+        Box::new(hook)
+    }
+}
+
+impl<F: FnMut() + 'static> UnlinkedHookPolicy<F> for RcRefCell {
+    fn unlinked_hook(hook: F) -> Self::UnlinkedHook {
+        Box::new(hook)
+    }
+}
+
+impl<T: 'static> OwnedPolicy<T> for RcRefCell {
+    fn owned(value: T) -> Self::Owned {
+        Box::new(value)
+    }
+}
+
+// Arc + Mutex
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ArcMutex;
+
+impl LinkPolicy for ArcMutex {
+    type Shared<T> = Arc<T>;
+    type Lock<T> = Mutex<T>;
+    type Guard<'a, T: 'a> = MutexGuard<'a, T>;
+    type DropHook<R> = Box<dyn FnOnce(R) + Send>;
+    type UnlinkedHook = Box<dyn FnMut() + Send>;
+    type Owned = Box<dyn Any + Send>;
+
+    fn share<T>(value: T) -> Self::Shared<T> {
+        Arc::new(value)
+    }
+    fn ptr_eq<T>(this: &Self::Shared<T>, other: &Self::Shared<T>) -> bool {
+        Arc::ptr_eq(this, other)
+    }
+
+    fn new_lock<T>(value: T) -> Self::Lock<T> {
+        Mutex::new(value)
+    }
+    fn lock<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T> {
+        lock.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn try_lock<T>(
+        lock: &Self::Lock<T>,
+        policy: LockPolicy,
+    ) -> Result<Self::Guard<'_, T>, LinkError> {
+        acquire(
+            policy,
+            || lock.lock(),
+            || lock.try_lock(),
+            || lock.clear_poison(),
+        )
+    }
+
+    fn call_drop_hook<R>(hook: Self::DropHook<R>, receiver: R) {
+        hook(receiver)
+    }
+    fn call_unlinked_hook(hook: &mut Self::UnlinkedHook) {
+        hook()
+    }
+}
+
+impl<R, F: FnOnce(R) + Send + 'static> DropHookPolicy<R, F> for ArcMutex {
+    fn drop_hook(hook: F) -> Self::DropHook<R> {
+        Box::new(hook)
+    }
+}
+
+impl<F: FnMut() + Send + 'static> UnlinkedHookPolicy<F> for ArcMutex {
+    fn unlinked_hook(hook: F) -> Self::UnlinkedHook {
+        Box::new(hook)
+    }
+}
+
+impl<T: Send + 'static> OwnedPolicy<T> for ArcMutex {
+    fn owned(value: T) -> Self::Owned {
+        Box::new(value)
+    }
+}
+
+// Arc + RwLock
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ArcRwLock;
+
+impl LinkPolicy for ArcRwLock {
+    type Shared<T> = Arc<T>;
+    type Lock<T> = RwLock<T>;
+    type Guard<'a, T: 'a> = RwLockWriteGuard<'a, T>;
+    type DropHook<R> = Box<dyn FnOnce(R) + Send + Sync>;
+    type UnlinkedHook = Box<dyn FnMut() + Send + Sync>;
+    type Owned = Box<dyn Any + Send + Sync>;
+
+    fn share<T>(value: T) -> Self::Shared<T> {
+        Arc::new(value)
+    }
+    fn ptr_eq<T>(this: &Self::Shared<T>, other: &Self::Shared<T>) -> bool {
+        Arc::ptr_eq(this, other)
+    }
+
+    fn new_lock<T>(value: T) -> Self::Lock<T> {
+        RwLock::new(value)
+    }
+    fn lock<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T> {
+        lock.write().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn try_lock<T>(
+        lock: &Self::Lock<T>,
+        policy: LockPolicy,
+    ) -> Result<Self::Guard<'_, T>, LinkError> {
+        acquire(
+            policy,
+            || lock.write(),
+            || lock.try_write(),
+            || lock.clear_poison(),
+        )
+    }
+
+    fn call_drop_hook<R>(hook: Self::DropHook<R>, receiver: R) {
+        hook(receiver)
+    }
+    fn call_unlinked_hook(hook: &mut Self::UnlinkedHook) {
+        hook()
+    }
+}
+
+impl<R, F: FnOnce(R) + Send + Sync + 'static> DropHookPolicy<R, F> for ArcRwLock {
+    fn drop_hook(hook: F) -> Self::DropHook<R> {
+        Box::new(hook)
+    }
+}
+
+impl<F: FnMut() + Send + Sync + 'static> UnlinkedHookPolicy<F> for ArcRwLock {
+    fn unlinked_hook(hook: F) -> Self::UnlinkedHook {
+        Box::new(hook)
+    }
+}
+
+impl<T: Send + Sync + 'static> OwnedPolicy<T> for ArcRwLock {
+    fn owned(value: T) -> Self::Owned {
+        Box::new(value)
+    }
+}
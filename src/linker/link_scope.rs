@@ -0,0 +1,154 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::{
+    link_policy::{LinkPolicy, OwnedPolicy},
+    linked::Linked,
+    revoke_token::RevokeToken,
+    Linker,
+};
+
+struct ScopeState<P: LinkPolicy> {
+    closed: AtomicBool,
+    members: P::Lock<Vec<RevokeToken<P>>>,
+    owned: P::Lock<Vec<P::Owned>>,
+    // a dropped child scope closes itself, so closed children are the ones that can be forgotten
+    children: P::Lock<Vec<P::Shared<ScopeState<P>>>>,
+}
+
+impl<P: LinkPolicy> ScopeState<P> {
+    fn new() -> Self {
+        Self {
+            closed: AtomicBool::new(false),
+            members: P::new_lock(Vec::new()),
+            owned: P::new_lock(Vec::new()),
+            children: P::new_lock(Vec::new()),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    fn close(&self) {
+        if self.closed.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let members = std::mem::take(&mut *P::lock(&self.members));
+        for member in members {
+            member.revoke();
+        }
+
+        // dropping the owned linkers may run their drop hooks, so nothing is locked here
+        let owned = std::mem::take(&mut *P::lock(&self.owned));
+        drop(owned);
+
+        let children = std::mem::take(&mut *P::lock(&self.children));
+        for child in children {
+            child.close();
+        }
+    }
+
+    fn live_count(&self) -> usize {
+        let members = P::lock(&self.members)
+            .iter()
+            .filter(|member| member.is_linked() && !member.is_revoked())
+            .count();
+
+        let children = P::lock(&self.children).clone();
+        members
+            + children
+                .iter()
+                .map(|child| child.live_count())
+                .sum::<usize>()
+    }
+}
+
+pub struct LinkScope<P: LinkPolicy> {
+    state: P::Shared<ScopeState<P>>,
+}
+
+impl<P: LinkPolicy> LinkScope<P> {
+    pub fn new() -> Self {
+        Self {
+            state: P::share(ScopeState::new()),
+        }
+    }
+
+    pub fn child(&self) -> Self {
+        let child = Self::new();
+        let mut children = P::lock(&self.state.children);
+        if self.is_closed() {
+            drop(children);
+            child.close();
+        } else {
+            children.retain(|child| !child.is_closed());
+            children.push(child.state.clone());
+        }
+        child
+    }
+
+    pub fn link<R>(&self, linker: &Linker<R, P>) -> Linked<R, P> {
+        let (linked, token) = linker.linked_revocable();
+        self.insert(token);
+        linked
+    }
+
+    pub fn adopt<R>(&self, receiver: R) -> Linked<R, P>
+    where
+        P: OwnedPolicy<Linker<R, P>>,
+    {
+        let linker = Linker::new(receiver);
+        let linked = self.link(&linker);
+        let mut owned = P::lock(&self.state.owned);
+        if !self.is_closed() {
+            owned.push(P::owned(linker));
+        }
+        linked
+    }
+
+    pub fn insert(&self, token: RevokeToken<P>) {
+        let mut members = P::lock(&self.state.members);
+        if self.is_closed() {
+            token.revoke();
+        } else {
+            members.retain(RevokeToken::is_linked);
+            members.push(token);
+        }
+    }
+
+    pub fn close(&self) {
+        self.state.close();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state.is_closed()
+    }
+
+    pub fn live_count(&self) -> usize {
+        self.state.live_count()
+    }
+}
+
+impl<P: LinkPolicy> Default for LinkScope<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: LinkPolicy> Drop for LinkScope<P> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl<P: LinkPolicy> std::fmt::Debug for LinkScope<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{closed: {}, live: {}}}",
+            self.is_closed(),
+            self.live_count()
+        )
+    }
+}
@@ -0,0 +1,165 @@
+use std::sync::atomic::Ordering;
+
+use crate::{
+    link_id::LinkId,
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+
+use super::{
+    display_receiver,
+    link_error::LinkError,
+    link_policy::LinkPolicy,
+    lock_policy::{LockPolicy, PoisonPolicy},
+    Handle, Link,
+};
+
+pub struct Linked<R, P: LinkPolicy> {
+    pub(super) link: P::Shared<Link<R, P>>,
+    pub(super) id: LinkId,
+    pub(super) handle: P::Shared<Handle>,
+    pub(super) policy: LockPolicy,
+}
+
+impl<R, P: LinkPolicy> Linked<R, P> {
+    pub fn get_receiver(&self) -> &P::Lock<Option<R>> {
+        &self.link.receiver
+    }
+
+    pub fn id(&self) -> LinkId {
+        self.id
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.handle.revoked.load(Ordering::Acquire)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.link.alive.load(Ordering::Acquire) && !self.is_revoked()
+    }
+
+    pub fn link_count(&self) -> usize {
+        self.link.links.load(Ordering::Acquire)
+    }
+
+    pub fn policy(&self) -> LockPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: LockPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn try_send<E>(&mut self, event: E) -> Result<ReceiverResult<E, R::Output>, LinkError<E>>
+    where
+        R: Receive<E>,
+    {
+        if self.is_revoked() {
+            return Ok(ReceiverResult::Delete(event));
+        }
+
+        match P::try_lock(&self.link.receiver, self.policy) {
+            Ok(guard) => Ok(self.link.dispatch(guard, |receiver| match receiver {
+                Some(t0) => t0.send(event),
+                None => ReceiverResult::Delete(event),
+            })),
+            Err(LinkError::Busy(())) => Err(LinkError::Busy(event)),
+            Err(LinkError::Poisoned(())) => match self.policy.poison {
+                PoisonPolicy::Delete => Ok(ReceiverResult::Delete(event)),
+                _ => Err(LinkError::Poisoned(event)),
+            },
+        }
+    }
+
+    pub fn try_view<E>(&mut self, event: &E) -> Result<Option<DeleteView>, LinkError>
+    where
+        R: View<E>,
+    {
+        if self.is_revoked() {
+            return Ok(Some(DeleteView));
+        }
+
+        match P::try_lock(&self.link.receiver, self.policy) {
+            Ok(guard) => Ok(self.link.dispatch(guard, |receiver| match receiver {
+                Some(viewer) => viewer.view(event),
+                None => Some(DeleteView),
+            })),
+            Err(LinkError::Poisoned(())) if self.policy.poison == PoisonPolicy::Delete => {
+                Ok(Some(DeleteView))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<R, P: LinkPolicy> Clone for Linked<R, P> {
+    fn clone(&self) -> Self {
+        self.link.relink(&self.handle);
+        Self {
+            link: self.link.clone(),
+            id: self.id,
+            handle: self.handle.clone(),
+            policy: self.policy,
+        }
+    }
+}
+
+impl<R, P: LinkPolicy> Drop for Linked<R, P> {
+    fn drop(&mut self) {
+        self.link.unlink(self.id, &self.handle);
+    }
+}
+
+/// a [`Busy`][`LinkError::Busy`] receiver returns [`Stop`][`ReceiverResult::Stop`], so inside a router the event is
+/// dropped exactly as if the receiver had stopped it, use [`try_send`][`Linked::try_send`] to get busy events back
+impl<E, R: Receive<E>, P: LinkPolicy> Receive<E> for Linked<R, P> {
+    type Output = R::Output;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        match self.try_send(event) {
+            Ok(result) => result,
+            Err(LinkError::Busy(_)) => ReceiverResult::Stop,
+            Err(LinkError::Poisoned(_)) => panic!("linked receiver is poisoned"),
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        Linked::is_alive(self)
+    }
+}
+
+/// a [`Busy`][`LinkError::Busy`] viewer skips the event
+impl<E, R: View<E>, P: LinkPolicy> View<E> for Linked<R, P> {
+    fn view(&mut self, event: &E) -> Option<DeleteView> {
+        match self.try_view(event) {
+            Ok(result) => result,
+            Err(LinkError::Busy(())) => None,
+            Err(LinkError::Poisoned(())) => panic!("linked viewer is poisoned"),
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        Linked::is_alive(self)
+    }
+}
+
+impl<R, P: LinkPolicy> std::fmt::Debug for Linked<R, P>
+where
+    P::Lock<Option<R>>: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{id: {}, links: {}, receiver: {:?}}}",
+            self.id,
+            self.link_count(),
+            self.link.receiver
+        )
+    }
+}
+
+impl<R: std::fmt::Display, P: LinkPolicy> std::fmt::Display for Linked<R, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display_receiver::<R, P>(&self.link.receiver, f)
+    }
+}
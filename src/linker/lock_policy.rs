@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PoisonPolicy {
+    /// use the inner value of the poisoned lock as if nothing happened, this is also what the plain `Rc`/`Arc`
+    /// wrapped `Mutex` and `RwLock` receivers and viewers do, they used to panic and have no policy to configure
+    #[default]
+    Recover,
+    /// treat the receiver as deleted
+    Delete,
+    /// hand the poisoning back to the caller as a [`LinkError::Poisoned`][`super::link_error::LinkError::Poisoned`]
+    Propagate,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LockWait {
+    /// block until the lock is acquired
+    #[default]
+    Block,
+    /// give up straight away if the lock is held elsewhere
+    Try,
+    /// keep trying for at most the given duration, sleeping a little longer after each failed attempt, up to a few
+    /// milliseconds
+    Timeout(Duration),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LockPolicy {
+    pub poison: PoisonPolicy,
+    pub wait: LockWait,
+}
+
+impl LockPolicy {
+    pub fn new(poison: PoisonPolicy, wait: LockWait) -> Self {
+        Self { poison, wait }
+    }
+
+    pub fn with_poison(self, poison: PoisonPolicy) -> Self {
+        Self { poison, ..self }
+    }
+
+    pub fn with_wait(self, wait: LockWait) -> Self {
+        Self { wait, ..self }
+    }
+}
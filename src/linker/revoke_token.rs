@@ -0,0 +1,43 @@
+use std::sync::atomic::Ordering;
+
+use crate::link_id::LinkId;
+
+use super::{link_policy::LinkPolicy, Handle};
+
+pub struct RevokeToken<P: LinkPolicy> {
+    pub(super) id: LinkId,
+    pub(super) handle: P::Shared<Handle>,
+}
+
+impl<P: LinkPolicy> RevokeToken<P> {
+    pub fn id(&self) -> LinkId {
+        self.id
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.handle.revoked.load(Ordering::Acquire)
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.handle.instances.load(Ordering::Acquire) > 0
+    }
+
+    pub fn revoke(&self) {
+        self.handle.revoked.store(true, Ordering::Release);
+    }
+}
+
+impl<P: LinkPolicy> Clone for RevokeToken<P> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+impl<P: LinkPolicy> std::fmt::Debug for RevokeToken<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{id: {}, revoked: {}}}", self.id, self.is_revoked())
+    }
+}
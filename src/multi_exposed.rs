@@ -8,25 +8,74 @@ pub trait MultiExpose<I: ?Sized> {
     fn remove_viewer(&mut self, id: usize) -> Option<Box<I>>;
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __multi_expose_impl {
+    ($Name:ident, $viewers:ident, $I:ident) => {
+        impl<R> $crate::multi_exposed::MultiExpose<dyn $I> for $Name<R> {
+            fn get_viewers(
+                &self,
+            ) -> &$crate::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn $I>>
+            {
+                &self.$viewers
+            }
+            fn add_viewer(
+                &mut self,
+                other: ::std::boxed::Box<dyn $I>,
+            ) -> ::std::result::Result<usize, $crate::counted_map::HashMapFull> {
+                self.$viewers.push(other)
+            }
+            fn remove_viewer(
+                &mut self,
+                id: usize,
+            ) -> ::std::option::Option<::std::boxed::Box<dyn $I>> {
+                self.$viewers.remove(id)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __multi_collect_viewers {
+    ($viewers:expr, $I:ident for $($E:ty)|*) => {{
+        let viewers = $viewers;
+        let dead: ::std::vec::Vec<usize> = viewers
+            .iter()
+            .filter(|(_, viewer)| !(true $(&& <dyn $I as $crate::view::View<$E>>::is_alive(viewer.as_ref()))*))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in &dead {
+            viewers.remove(*id);
+        }
+        dead.len()
+    }};
+}
+
+#[macro_export]
 macro_rules! multi_exposed_trait {
-    ($vis:vis $I:ident for $($E:ty)|*) => {
-        $vis trait $I: $($crate::event_horizon::view::View<$E>+)* {}
-        impl<I: $($crate::event_horizon::view::View<$E>+)*> $I for I {}
+    ($vis:vis $I:ident $(<$($T:ident),*>)? for $($E:ty)|*) => {
+        $vis trait $I$(<$($T),*>)?: $($crate::view::View<$E>+)* {}
+        impl<$($($T,)*)? V: $($crate::view::View<$E>+)*> $I$(<$($T),*>)? for V {}
     };
 }
 
+/// generates a `struct Name<R>` generic over its receiver only, a struct with other generic parameters, bounds or
+/// extra fields needs `#[derive(MultiExposed)]` of the `derive` feature, which generates the same api
+#[macro_export]
 macro_rules! multi_exposed {
     (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($viewers:ident as $I:ident for $($E:ty => $Output:ty)|*),* } else { $($P:ty => $POutput:ty),* }) => {
         #[derive($($attr),*)]
         $vis struct $Name<R> {
-            $($viewers: $crate::event_horizon::counted_map::ReassignableCountedMap<usize, Box<dyn $I>>,)*
+            $($viewers: $crate::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn $I>>,)*
             receiver: R,
         }
 
         #[allow(unused)]
         impl<R> $Name<R> {
             pub fn new(receiver: R) -> Self {
-                Self { receiver, $($viewers: $crate::event_horizon::counted_map::ReassignableCountedMap::new(),)* }
+                Self { receiver, $($viewers: $crate::counted_map::ReassignableCountedMap::new(),)* }
             }
 
             pub fn get_receiver(&self) -> &R {
@@ -37,16 +86,20 @@ macro_rules! multi_exposed {
                 &mut self.receiver
             }
 
-            $(pub fn $viewers(&self) -> &$crate::event_horizon::counted_map::ReassignableCountedMap<usize, Box<dyn $I>> {
+            $(pub fn $viewers(&self) -> &$crate::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn $I>> {
                 &self.$viewers
             })*
+
+            pub fn collect_garbage(&mut self) -> usize {
+                0 $(+ $crate::__multi_collect_viewers!(&mut self.$viewers, $I for $($E)|*))*
+            }
         }
 
-        $($(impl<R: $crate::event_horizon::receive::Receive<$E, Output = $Output>> $crate::event_horizon::receive::Receive<$E> for $Name<R> {
+        $($(impl<R: $crate::receive::Receive<$E, Output = $Output>> $crate::receive::Receive<$E> for $Name<R> {
             type Output = $Output;
 
-            fn send(&mut self, event: $E) -> $crate::event_horizon::receive::ReceiverResult<$E, Self::Output> {
-                let mut deleted = Vec::new();
+            fn send(&mut self, event: $E) -> $crate::receive::ReceiverResult<$E, Self::Output> {
+                let mut deleted = ::std::vec::Vec::new();
 
                 for (id, viewer) in self.$viewers.iter_mut() {
                     if viewer.view(&event).is_some() {
@@ -62,48 +115,37 @@ macro_rules! multi_exposed {
             }
         })*)*
 
-        $(impl<R: $crate::event_horizon::receive::Receive<$P, Output = $POutput>> $crate::event_horizon::receive::Receive<$P> for $Name<R> {
+        $(impl<R: $crate::receive::Receive<$P, Output = $POutput>> $crate::receive::Receive<$P> for $Name<R> {
             type Output = $POutput;
 
-            fn send(&mut self, event: $P) -> $crate::event_horizon::receive::ReceiverResult<$P, Self::Output> {
+            fn send(&mut self, event: $P) -> $crate::receive::ReceiverResult<$P, Self::Output> {
                 self.receiver.send(event)
             }
         })*
 
-        $(impl<R> $crate::event_horizon::multi_exposed::MultiExpose<dyn $I> for $Name<R> {
-            fn get_viewers(&self) -> &$crate::event_horizon::counted_map::ReassignableCountedMap<usize, Box<dyn $I>> {
-                &self.$viewers
-            }
-            fn add_viewer(&mut self, other: Box<dyn $I>) -> Result<usize, $crate::event_horizon::counted_map::HashMapFull> {
-                self.$viewers.push(other)
-            }
-            fn remove_viewer(&mut self, id: usize) -> Option<Box<dyn $I>> {
-                self.$viewers.remove(id)
-            }
-        })*
+        $($crate::__multi_expose_impl!($Name, $viewers, $I);)*
 
-        impl<R> Default for $Name<R> where R: Default {
+        impl<R> ::std::default::Default for $Name<R> where R: ::std::default::Default {
             fn default() -> Self {
                 Self::new(R::default())
             }
         }
 
-        impl<R> std::fmt::Debug for $Name<R> where R: std::fmt::Debug {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{{[")?;
-                $crate::event_horizon::multi_router::list_helper!(f, $($viewers, self.$viewers.len()),*);
-                write!(f, "], ")?;
-                write!(f, "{:?}}}", self.receiver)
+        impl<R> ::std::fmt::Debug for $Name<R> where R: ::std::fmt::Debug {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::write!(f, "{{[")?;
+                $crate::__multi_list_helper!(f, $($viewers, self.$viewers.len()),*);
+                ::std::write!(f, "], ")?;
+                ::std::write!(f, "{:?}}}", self.receiver)
             }
         }
 
-        impl<R> std::fmt::Display for $Name<R> where R: std::fmt::Display {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<R> ::std::fmt::Display for $Name<R> where R: ::std::fmt::Display {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 self.receiver.fmt(f)
             }
         }
     };
 }
 
-pub(crate) use multi_exposed;
-pub(crate) use multi_exposed_trait;
+pub use crate::{multi_exposed, multi_exposed_trait};
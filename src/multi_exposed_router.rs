@@ -0,0 +1,146 @@
+#![allow(unused)]
+
+/// generates a `struct Name<R>` generic over its receiver only, for other generic parameters, bounds or extra fields
+/// derive `MultiRouter` on a struct whose receiver is a `MultiExposed` one
+#[macro_export]
+macro_rules! multi_exposed_router {
+    (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty => $Output:ty)|*),* } else { $($P:ty => $POutput:ty),* }) => {
+        #[derive($($attr),*)]
+        $vis struct $Name<R> {
+            $(
+                $intercept: ::std::option::Option<::std::boxed::Box<dyn $I>>,
+                $viewers: $crate::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn $V>>,
+            )*
+            receiver: R,
+        }
+
+        #[allow(unused)]
+        impl<R> $Name<R> {
+            pub fn new(receiver: R) -> Self {
+                Self {
+                    receiver,
+                    $(
+                        $intercept: ::std::option::Option::None,
+                        $viewers: $crate::counted_map::ReassignableCountedMap::new(),
+                    )*
+                }
+            }
+
+            pub fn get_receiver(&self) -> &R {
+                &self.receiver
+            }
+
+            pub fn get_receiver_mut(&mut self) -> &mut R {
+                &mut self.receiver
+            }
+
+            $(pub fn $intercept(&self) -> ::std::option::Option<&dyn $I> {
+                self.$intercept.as_ref().map(::std::boxed::Box::as_ref)
+            }
+
+            pub fn $viewers(&self) -> &$crate::counted_map::ReassignableCountedMap<usize, ::std::boxed::Box<dyn $V>> {
+                &self.$viewers
+            })*
+
+            pub fn collect_garbage(&mut self) -> usize {
+                0 $(
+                    + <Self as $crate::multi_router::MultiRoute<dyn $I>>::collect_garbage(self)
+                    + $crate::__multi_collect_viewers!(&mut self.$viewers, $V for $($E)|*)
+                )*
+            }
+        }
+
+        $($(impl<R: $crate::receive::Receive<$E, Output = $Output>> $crate::receive::Receive<$E> for $Name<R> {
+            type Output = $Output;
+
+            fn send(&mut self, event: $E) -> $crate::receive::ReceiverResult<$E, Self::Output> {
+                let event = if let ::std::option::Option::Some(ref mut intercept) = self.$intercept {
+                    match intercept.send(event) {
+                        $crate::receive::ReceiverResult::Continue(event) => event,
+                        $crate::receive::ReceiverResult::Stop => return $crate::receive::ReceiverResult::Stop,
+                        $crate::receive::ReceiverResult::Delete(event) => {
+                            (self as &mut dyn $crate::multi_router::MultiRoute<dyn $I>).delete_top_intercept().unwrap();
+                            event
+                        }
+                    }
+                } else {
+                    event
+                };
+
+                let mut deleted = ::std::vec::Vec::new();
+
+                for (id, viewer) in self.$viewers.iter_mut() {
+                    if viewer.view(&event).is_some() {
+                        deleted.push(*id);
+                    }
+                }
+
+                for id in deleted {
+                    self.$viewers.remove(id);
+                }
+
+                self.receiver.send(event)
+            }
+        })*)*
+
+        $(impl<R: $crate::receive::Receive<$P, Output = $POutput>> $crate::receive::Receive<$P> for $Name<R> {
+            type Output = $POutput;
+
+            fn send(&mut self, event: $P) -> $crate::receive::ReceiverResult<$P, Self::Output> {
+                self.receiver.send(event)
+            }
+        })*
+
+        $(
+            $crate::__multi_route_impl!($Name, $intercept, $I);
+            $crate::__multi_expose_impl!($Name, $viewers, $V);
+        )*
+
+        impl<R> ::std::default::Default for $Name<R> where R: ::std::default::Default {
+            fn default() -> Self {
+                Self::new(R::default())
+            }
+        }
+
+        impl<R> ::std::fmt::Debug for $Name<R> where R: ::std::fmt::Debug {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::write!(f, "{{[")?;
+                $crate::__multi_list_helper!(f, $($intercept, match self.$intercept.as_ref() {
+                    ::std::option::Option::Some(_) => "intercepted",
+                    ::std::option::Option::None => "n/a",
+                }, $viewers, self.$viewers.len()),*);
+                ::std::write!(f, "], ")?;
+                ::std::write!(f, "{:?}}}", self.receiver)
+            }
+        }
+
+        impl<R> ::std::fmt::Display for $Name<R> where R: ::std::fmt::Display {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                self.receiver.fmt(f)
+            }
+        }
+    };
+    (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty => $Output:ty)|*),* }) => {
+        $crate::multi_exposed_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I + $viewers as $V where $($E => $Output)|*),* } else {});
+    };
+    (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty)|*),* } else { $($P:ty),* }) => {
+        $crate::multi_exposed_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I + $viewers as $V where $($E => $E)|*),* } else { $($P => $P),* });
+    };
+    (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty)|*),* }) => {
+        $crate::multi_exposed_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I + $viewers as $V where $($E)|*),* } else {});
+    };
+    ($vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty => $Output:ty)|*),* } else { $($P:ty => $POutput:ty),* }) => {
+        $crate::multi_exposed_router!(#[derive()] $vis $Name { $($intercept as $I + $viewers as $V where $($E => $Output)|*),* } else { $($P => $POutput),* });
+    };
+    ($vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty => $Output:ty)|*),* }) => {
+        $crate::multi_exposed_router!(#[derive()] $vis $Name { $($intercept as $I + $viewers as $V where $($E => $Output)|*),* } else {});
+    };
+    ($vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty)|*),* } else { $($P:ty),* }) => {
+        $crate::multi_exposed_router!(#[derive()] $vis $Name { $($intercept as $I + $viewers as $V where $($E => $E)|*),* } else { $($P => $P),* });
+    };
+    ($vis:vis $Name:ident { $($intercept:ident as $I:ident + $viewers:ident as $V:ident where $($E:ty)|*),* }) => {
+        $crate::multi_exposed_router!(#[derive()] $vis $Name { $($intercept as $I + $viewers as $V where $($E)|*),* } else {});
+    };
+}
+
+pub use crate::multi_exposed_router;
@@ -1,57 +1,124 @@
 #![allow(unused)]
 
-macro_rules! list_helper {
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __multi_list_helper {
     ($f:ident, $last:ident, $value:expr) => {
-        write!($f, "{}: {}",stringify!($last), $value)?;
+        ::std::write!($f, "{}: {}",::std::stringify!($last), $value)?;
     };
     ($f:ident, $first:ident, $value:expr, $($rest:ident, $rest_value:expr),*) => {
-        write!($f, "{}: {}, ",stringify!($first), $value)?;
-        $crate::event_horizon::multi_router::list_helper!($f, $($rest, $rest_value),*)
+        ::std::write!($f, "{}: {}, ",::std::stringify!($first), $value)?;
+        $crate::__multi_list_helper!($f, $($rest, $rest_value),*)
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __multi_route_impl {
+    ($Name:ident, $intercept:ident, $I:ident) => {
+        impl<R> $crate::multi_router::MultiRoute<dyn $I> for $Name<R> {
+            fn take_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn $I>> {
+                self.$intercept.take()
+            }
+
+            fn delete_top_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn $I>> {
+                let mut old_intercept = self.take_intercept();
+                if let ::std::option::Option::Some(ref mut intercept) = old_intercept {
+                    self.$intercept =
+                        (intercept as &mut ::std::boxed::Box<dyn $I>).take_intercept();
+                }
+                old_intercept
+            }
+
+            fn intercept(&mut self, intercept: ::std::boxed::Box<dyn $I>) {
+                match self.$intercept {
+                    ::std::option::Option::Some(ref mut child) => child.intercept(intercept),
+                    ::std::option::Option::None => {
+                        self.$intercept = ::std::option::Option::Some(intercept)
+                    }
+                }
+            }
+
+            fn collect_garbage(&mut self) -> usize {
+                let mut removed = 0;
+                while self
+                    .$intercept
+                    .as_ref()
+                    .is_some_and(|intercept| !$I::is_alive(intercept.as_ref()))
+                {
+                    (self as &mut dyn $crate::multi_router::MultiRoute<dyn $I>)
+                        .delete_top_intercept();
+                    removed += 1;
+                }
+
+                match self.$intercept {
+                    ::std::option::Option::Some(ref mut intercept) => {
+                        removed + $I::collect_garbage(intercept.as_mut())
+                    }
+                    ::std::option::Option::None => removed,
+                }
+            }
+        }
+    };
+}
+
 pub trait MultiRoute<I: ?Sized> {
     fn take_intercept(&mut self) -> Option<Box<I>>;
     fn delete_top_intercept(&mut self) -> Option<Box<I>>;
     fn intercept(&mut self, intercept: Box<I>);
+    fn collect_garbage(&mut self) -> usize {
+        0
+    }
 }
 
-#[allow(unused)]
+#[macro_export]
 macro_rules! multi_router_intercept_trait {
-    ($vis:vis $I:ident for $($E:ty)|*) => {
-        $vis trait $I: $($crate::event_horizon::receive::Receive<$E, Output = $E>+)* {
-            fn take_intercept(&mut self) -> Option<Box<dyn $I>>;
-            fn intercept(&mut self, intercept: Box<dyn $I>);
+    ($vis:vis $I:ident $(<$($T:ident),*>)? for $($E:ty)|*) => {
+        $vis trait $I$(<$($T),*>)?: $($crate::receive::Receive<$E, Output = $E>+)* {
+            fn take_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn $I$(<$($T),*>)?>>;
+            fn intercept(&mut self, intercept: ::std::boxed::Box<dyn $I$(<$($T),*>)?>);
+            fn is_alive(&self) -> bool;
+            fn collect_garbage(&mut self) -> usize {
+                0
+            }
         }
     };
 }
 
-#[allow(unused)]
+#[macro_export]
 macro_rules! impl_multi_router_intercept_trait {
     ($Name:ident as $I:ident for $($E:ty)|*) => {
-        impl<R: $($crate::event_horizon::receive::Receive<$E, Output = $E>+)*> $I for $Name<R> {
-            fn take_intercept(&mut self) -> Option<Box<dyn $I>> {
-                (self as &mut dyn crate::event_horizon::multi_router::MultiRoute<dyn $I>).take_intercept()
+        impl<R: $($crate::receive::Receive<$E, Output = $E>+)*> $I for $Name<R> {
+            fn take_intercept(&mut self) -> ::std::option::Option<::std::boxed::Box<dyn $I>> {
+                (self as &mut dyn $crate::multi_router::MultiRoute<dyn $I>).take_intercept()
+            }
+            fn intercept(&mut self, intercept: ::std::boxed::Box<dyn $I>) {
+                (self as &mut dyn $crate::multi_router::MultiRoute<dyn $I>).intercept(intercept)
+            }
+            fn is_alive(&self) -> bool {
+                true $(&& <R as $crate::receive::Receive<$E>>::is_alive(&self.receiver))*
             }
-            fn intercept(&mut self, intercept: Box<dyn $I>) {
-                (self as &mut dyn crate::event_horizon::multi_router::MultiRoute<dyn $I>).intercept(intercept)
+            fn collect_garbage(&mut self) -> usize {
+                (self as &mut dyn $crate::multi_router::MultiRoute<dyn $I>).collect_garbage()
             }
         }
     };
 }
 
-#[allow(unused)]
+/// generates a `struct Name<R>` generic over its receiver only, a struct with other generic parameters, bounds or
+/// extra fields needs `#[derive(MultiRouter)]` of the `derive` feature, which generates the same api
+#[macro_export]
 macro_rules! multi_router {
     (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty => $Output:ty)|*),* } else { $($P:ty => $POutput:ty),* }) => {
         #[derive($($attr),*)]
         $vis struct $Name<R> {
-            $($intercept: Option<Box<dyn $I>>,)*
+            $($intercept: ::std::option::Option<::std::boxed::Box<dyn $I>>,)*
             receiver: R,
         }
 
         impl<R> $Name<R> {
             pub fn new(receiver: R) -> Self {
-                Self { receiver, $($intercept: None),* }
+                Self { receiver, $($intercept: ::std::option::Option::None),* }
             }
 
             #[allow(unused)]
@@ -64,21 +131,26 @@ macro_rules! multi_router {
                 &mut self.receiver
             }
 
-            $(pub fn $intercept (&self) -> Option<&dyn $I> {
-                self.$intercept.as_ref().map(Box::as_ref)
+            $(pub fn $intercept (&self) -> ::std::option::Option<&dyn $I> {
+                self.$intercept.as_ref().map(::std::boxed::Box::as_ref)
             })*
+
+            #[allow(unused)]
+            pub fn collect_garbage(&mut self) -> usize {
+                0 $(+ <Self as $crate::multi_router::MultiRoute<dyn $I>>::collect_garbage(self))*
+            }
         }
 
-        $($(impl<R: $crate::event_horizon::receive::Receive<$E, Output = $Output>> $crate::event_horizon::receive::Receive<$E> for $Name<R> {
+        $($(impl<R: $crate::receive::Receive<$E, Output = $Output>> $crate::receive::Receive<$E> for $Name<R> {
             type Output = $Output;
 
-            fn send(&mut self, event: $E) -> $crate::event_horizon::receive::ReceiverResult<$E, Self::Output> {
-                let event = if let Some(ref mut intercept) = self.$intercept {
+            fn send(&mut self, event: $E) -> $crate::receive::ReceiverResult<$E, Self::Output> {
+                let event = if let ::std::option::Option::Some(ref mut intercept) = self.$intercept {
                     match intercept.send(event) {
-                        $crate::event_horizon::receive::ReceiverResult::Continue(event) => event,
-                        $crate::event_horizon::receive::ReceiverResult::Stop => return $crate::event_horizon::receive::ReceiverResult::Stop,
-                        $crate::event_horizon::receive::ReceiverResult::Delete(event) => {
-                            (self as &mut dyn $crate::event_horizon::multi_router::MultiRoute<dyn $I>).delete_top_intercept().unwrap();
+                        $crate::receive::ReceiverResult::Continue(event) => event,
+                        $crate::receive::ReceiverResult::Stop => return $crate::receive::ReceiverResult::Stop,
+                        $crate::receive::ReceiverResult::Delete(event) => {
+                            (self as &mut dyn $crate::multi_router::MultiRoute<dyn $I>).delete_top_intercept().unwrap();
                             event
                         }
                     }
@@ -90,83 +162,61 @@ macro_rules! multi_router {
             }
         })*)*
 
-        $(impl<R: $crate::event_horizon::receive::Receive<$P, Output = $POutput>> $crate::event_horizon::receive::Receive<$P> for $Name<R> {
+        $(impl<R: $crate::receive::Receive<$P, Output = $POutput>> $crate::receive::Receive<$P> for $Name<R> {
             type Output = $POutput;
 
-            fn send(&mut self, event: $P) -> $crate::event_horizon::receive::ReceiverResult<$P, Self::Output> {
+            fn send(&mut self, event: $P) -> $crate::receive::ReceiverResult<$P, Self::Output> {
                 self.receiver.send(event)
             }
         })*
 
-        $(impl<R> $crate::event_horizon::multi_router::MultiRoute<dyn $I> for $Name<R> {
-            fn take_intercept(&mut self) -> Option<Box<dyn $I>> {
-                self.$intercept.take()
-            }
-
-            fn delete_top_intercept(&mut self) -> Option<Box<dyn $I>> {
-                let mut old_intercept = self.take_intercept();
-                if let Some(ref mut intercept) = old_intercept {
-                    self.$intercept = (intercept as &mut Box<dyn $I>).take_intercept();
-                }
-                old_intercept
-            }
-
-            fn intercept(&mut self, intercept: Box<dyn $I>) {
-                match self.$intercept {
-                    Some(ref mut child) => child.intercept(intercept),
-                    None => self.$intercept = Some(intercept),
-                }
-            }
-        })*
+        $($crate::__multi_route_impl!($Name, $intercept, $I);)*
 
-        impl<R> Default for $Name<R> where R: Default {
+        impl<R> ::std::default::Default for $Name<R> where R: ::std::default::Default {
             fn default() -> Self {
                 Self::new(R::default())
             }
         }
 
-        impl<R> std::fmt::Debug for $Name<R> where R: std::fmt::Debug {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{{[")?;
-                $crate::event_horizon::multi_router::list_helper!(f, $($intercept, match self.$intercept.as_ref() {
-                    Some(_) => "intercepted",
-                    None => "n/a",
+        impl<R> ::std::fmt::Debug for $Name<R> where R: ::std::fmt::Debug {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::write!(f, "{{[")?;
+                $crate::__multi_list_helper!(f, $($intercept, match self.$intercept.as_ref() {
+                    ::std::option::Option::Some(_) => "intercepted",
+                    ::std::option::Option::None => "n/a",
                 }),*);
-                write!(f, "], ")?;
-                write!(f, "{:?}}}", self.receiver)
+                ::std::write!(f, "], ")?;
+                ::std::write!(f, "{:?}}}", self.receiver)
             }
         }
 
-        impl<R> std::fmt::Display for $Name<R> where R: std::fmt::Display {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<R> ::std::fmt::Display for $Name<R> where R: ::std::fmt::Display {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 self.receiver.fmt(f)
             }
         }
     };
     (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty => $Output:ty)|*),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I where $($E => $Output)|*),* } else {});
+        $crate::multi_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I where $($E => $Output)|*),* } else {});
     };
     (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty)|*),* } else { $($P:ty),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I where $($E => $E)|*),* } else { $($P => $P),* });
+        $crate::multi_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I where $($E => $E)|*),* } else { $($P => $P),* });
     };
     (#[derive($($attr:ident),*)] $vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty)|*),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I where $($E)|*),* } else {});
+        $crate::multi_router!(#[derive($($attr),*)] $vis $Name { $($intercept as $I where $($E)|*),* } else {});
     };
     ($vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty => $Output:ty)|*),* } else { $($P:ty => $POutput:ty),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E => $Output)|*),* } else { $($P => $POutput),* });
+        $crate::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E => $Output)|*),* } else { $($P => $POutput),* });
     };
     ($vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty => $Output:ty)|*),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E => $Output)|*),* } else {});
+        $crate::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E => $Output)|*),* } else {});
     };
     ($vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty)|*),* } else { $($P:ty),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E => $E)|*),* } else { $($P => $P),* });
+        $crate::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E => $E)|*),* } else { $($P => $P),* });
     };
     ($vis:vis $Name:ident { $($intercept:ident as $I:ident where $($E:ty)|*),* }) => {
-        $crate::event_horizon::multi_router::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E)|*),* } else {});
+        $crate::multi_router!(#[derive()] $vis $Name { $($intercept as $I where $($E)|*),* } else {});
     };
 }
 
-pub(crate) use impl_multi_router_intercept_trait;
-pub(crate) use list_helper;
-pub(crate) use multi_router;
-pub(crate) use multi_router_intercept_trait;
+pub use crate::{impl_multi_router_intercept_trait, multi_router, multi_router_intercept_trait};
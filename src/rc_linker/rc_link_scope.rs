@@ -0,0 +1,3 @@
+use crate::linker::{link_policy::RcRefCell, link_scope::LinkScope};
+
+pub type RcLinkScope = LinkScope<RcRefCell>;
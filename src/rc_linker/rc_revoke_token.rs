@@ -0,0 +1,3 @@
+use crate::linker::{link_policy::RcRefCell, revoke_token::RevokeToken};
+
+pub type RcRevokeToken = RevokeToken<RcRefCell>;
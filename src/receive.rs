@@ -1,7 +1,7 @@
 use std::{
     cell::RefCell,
     rc::Rc,
-    sync::{Arc, Mutex, RwLock},
+    sync::{Arc, Mutex, PoisonError, RwLock},
 };
 
 pub mod pass_receiver;
@@ -10,9 +10,25 @@ pub trait Receive<E> {
     type Output;
 
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output>;
+
+    fn is_alive(&self) -> bool {
+        true
+    }
+
+    /// eagerly removes the dead intercepts and viewers held by the receiver, returning how many were removed
+    fn collect_garbage(&mut self) -> usize {
+        0
+    }
+}
+
+pub trait SharedReceive<E> {
+    type Output;
+
+    fn send(&self, event: E) -> ReceiverResult<E, Self::Output>;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReceiverResult<E, T> {
     Continue(T),
     Stop,
@@ -57,7 +73,9 @@ impl<E, R: Receive<E>> Receive<E> for Rc<Mutex<R>> {
     type Output = R::Output;
 
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
-        self.lock().unwrap().send(event)
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .send(event)
     }
 }
 
@@ -66,7 +84,9 @@ impl<E, R: Receive<E>> Receive<E> for Rc<RwLock<R>> {
     type Output = R::Output;
 
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
-        self.write().unwrap().send(event)
+        self.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .send(event)
     }
 }
 
@@ -75,7 +95,9 @@ impl<E, R: Receive<E>> Receive<E> for Arc<Mutex<R>> {
     type Output = R::Output;
 
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
-        self.lock().unwrap().send(event)
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .send(event)
     }
 }
 
@@ -84,6 +106,8 @@ impl<E, R: Receive<E>> Receive<E> for Arc<RwLock<R>> {
     type Output = R::Output;
 
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
-        self.write().unwrap().send(event)
+        self.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .send(event)
     }
 }
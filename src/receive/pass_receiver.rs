@@ -3,6 +3,7 @@ use crate::view::{DeleteView, View};
 use super::{Receive, ReceiverResult};
 
 #[derive(Clone, Copy, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PassReceiver;
 
 impl<E> Receive<E> for PassReceiver {
@@ -0,0 +1,236 @@
+use std::{
+    sync::mpsc::Sender,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome {
+    Continue,
+    Stop,
+    Delete,
+}
+
+impl Outcome {
+    pub fn of<E, T>(result: &ReceiverResult<E, T>) -> Self {
+        match result {
+            ReceiverResult::Continue(_) => Outcome::Continue,
+            ReceiverResult::Stop => Outcome::Stop,
+            ReceiverResult::Delete(_) => Outcome::Delete,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Record<E> {
+    pub sequence: u64,
+    /// time since the recorder was created
+    pub elapsed: Duration,
+    pub event: E,
+    /// only known when the send was recorded through [`Recorder::record_send`] or a [`Recorded`] receiver
+    pub outcome: Option<Outcome>,
+}
+
+pub trait RecordSink<E> {
+    /// returns false once the sink can't take any more records, deleting the recorder
+    fn write(&mut self, record: Record<E>) -> bool;
+}
+
+impl<E> RecordSink<E> for Vec<Record<E>> {
+    fn write(&mut self, record: Record<E>) -> bool {
+        self.push(record);
+        true
+    }
+}
+
+impl<E> RecordSink<E> for Sender<Record<E>> {
+    fn write(&mut self, record: Record<E>) -> bool {
+        self.send(record).is_ok()
+    }
+}
+
+pub struct Recorder<E, S = Vec<Record<E>>> {
+    sink: S,
+    sequence: u64,
+    start: Instant,
+    closed: bool,
+    _event: std::marker::PhantomData<fn(E)>,
+}
+
+impl<E> Recorder<E> {
+    pub fn new() -> Self {
+        Self::with_sink(Vec::new())
+    }
+
+    pub fn records(&self) -> &[Record<E>] {
+        &self.sink
+    }
+
+    pub fn take_records(&mut self) -> Vec<Record<E>> {
+        std::mem::take(&mut self.sink)
+    }
+}
+
+impl<E, S: RecordSink<E>> Recorder<E, S> {
+    pub fn with_sink(sink: S) -> Self {
+        Self {
+            sink,
+            sequence: 0,
+            start: Instant::now(),
+            closed: false,
+            _event: std::marker::PhantomData,
+        }
+    }
+
+    pub fn get_sink(&self) -> &S {
+        &self.sink
+    }
+
+    pub fn get_sink_mut(&mut self) -> &mut S {
+        &mut self.sink
+    }
+
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    fn write(&mut self, event: E, outcome: Option<Outcome>) {
+        let record = Record {
+            sequence: self.sequence,
+            elapsed: self.start.elapsed(),
+            event,
+            outcome,
+        };
+        self.sequence += 1;
+        self.closed |= !self.sink.write(record);
+    }
+
+    /// sends the event to `receiver`, recording it along with the outcome
+    pub fn record_send<R: Receive<E>>(
+        &mut self,
+        receiver: &mut R,
+        event: E,
+    ) -> ReceiverResult<E, R::Output>
+    where
+        E: Clone,
+    {
+        let recorded = event.clone();
+        let result = receiver.send(event);
+        self.write(recorded, Some(Outcome::of(&result)));
+        result
+    }
+}
+
+impl<E: Clone, S: RecordSink<E>> View<E> for Recorder<E, S> {
+    fn view(&mut self, event: &E) -> Option<DeleteView> {
+        self.write(event.clone(), None);
+        self.closed.then_some(DeleteView)
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.closed
+    }
+}
+
+impl<E: Clone, S: RecordSink<E>> Receive<E> for Recorder<E, S> {
+    type Output = E;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        self.write(event.clone(), None);
+        match self.closed {
+            true => ReceiverResult::Delete(event),
+            false => ReceiverResult::Continue(event),
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.closed
+    }
+}
+
+/// wraps a receiver, recording every event sent to it along with the outcome, unlike a [`Recorder`] used as a viewer
+/// or intercept
+pub struct Recorded<E, R, S = Vec<Record<E>>> {
+    recorder: Recorder<E, S>,
+    receiver: R,
+}
+
+impl<E, R> Recorded<E, R> {
+    pub fn new(receiver: R) -> Self {
+        Self::with_recorder(Recorder::new(), receiver)
+    }
+}
+
+impl<E, R, S> Recorded<E, R, S> {
+    pub fn with_recorder(recorder: Recorder<E, S>, receiver: R) -> Self {
+        Self { recorder, receiver }
+    }
+
+    pub fn get_recorder(&self) -> &Recorder<E, S> {
+        &self.recorder
+    }
+
+    pub fn get_recorder_mut(&mut self) -> &mut Recorder<E, S> {
+        &mut self.recorder
+    }
+
+    pub fn get_receiver(&self) -> &R {
+        &self.receiver
+    }
+
+    pub fn get_receiver_mut(&mut self) -> &mut R {
+        &mut self.receiver
+    }
+
+    pub fn into_parts(self) -> (Recorder<E, S>, R) {
+        (self.recorder, self.receiver)
+    }
+}
+
+/// events keep reaching the receiver once the sink is closed, they just aren't recorded
+impl<E: Clone, R: Receive<E>, S: RecordSink<E>> Receive<E> for Recorded<E, R, S> {
+    type Output = R::Output;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        if self.recorder.closed {
+            return self.receiver.send(event);
+        }
+        self.recorder.record_send(&mut self.receiver, event)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.receiver.is_alive()
+    }
+
+    fn collect_garbage(&mut self) -> usize {
+        self.receiver.collect_garbage()
+    }
+}
+
+impl<E, R: std::fmt::Debug, S> std::fmt::Debug for Recorded<E, R, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{recorded: {}, receiver: {:?}}}",
+            self.recorder.sequence, self.receiver
+        )
+    }
+}
+
+impl<E> Default for Recorder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, S> std::fmt::Debug for Recorder<E, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{recorded: {}}}", self.sequence)
+    }
+}
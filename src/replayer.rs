@@ -0,0 +1,128 @@
+pub mod replay_error;
+
+use std::{
+    collections::VecDeque,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    receive::Receive,
+    recorder::{Outcome, Record},
+};
+
+use self::replay_error::ReplayError;
+
+pub trait Clock {
+    /// called before each event is replayed with the time it was originally recorded at
+    fn advance_to(&mut self, elapsed: Duration);
+}
+
+/// a clock that never waits, only keeping track of the recorded time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VirtualClock {
+    now: Duration,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn now(&self) -> Duration {
+        self.now
+    }
+}
+
+impl Clock for VirtualClock {
+    fn advance_to(&mut self, elapsed: Duration) {
+        self.now = self.now.max(elapsed);
+    }
+}
+
+/// a clock that sleeps until the recorded time has passed since it was created
+#[derive(Clone, Copy, Debug)]
+pub struct RealClock {
+    start: Instant,
+}
+
+impl RealClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for RealClock {
+    fn advance_to(&mut self, elapsed: Duration) {
+        thread::sleep(elapsed.saturating_sub(self.start.elapsed()));
+    }
+}
+
+impl Default for RealClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Replayer<E> {
+    records: VecDeque<Record<E>>,
+}
+
+impl<E> Replayer<E> {
+    pub fn new(records: impl IntoIterator<Item = Record<E>>) -> Self {
+        Self {
+            records: records.into_iter().collect(),
+        }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.records.len()
+    }
+
+    /// sends the next record, checking its outcome if one was recorded
+    pub fn step<R: Receive<E>>(&mut self, receiver: &mut R) -> Option<Result<(), ReplayError>> {
+        let record = self.records.pop_front()?;
+        let actual = Outcome::of(&receiver.send(record.event));
+        Some(match record.outcome {
+            Some(expected) if expected != actual => Err(ReplayError {
+                sequence: record.sequence,
+                expected,
+                actual,
+            }),
+            _ => Ok(()),
+        })
+    }
+
+    /// sends every record as fast as possible, stopping at the first mismatched outcome
+    pub fn replay<R: Receive<E>>(&mut self, receiver: &mut R) -> Result<usize, ReplayError> {
+        let mut replayed = 0;
+        while let Some(result) = self.step(receiver) {
+            result?;
+            replayed += 1;
+        }
+        Ok(replayed)
+    }
+
+    /// like [`replay`][`Replayer::replay`], advancing `clock` to the recorded time of each record first
+    pub fn replay_timed<R: Receive<E>>(
+        &mut self,
+        receiver: &mut R,
+        clock: &mut impl Clock,
+    ) -> Result<usize, ReplayError> {
+        let mut replayed = 0;
+        while let Some(elapsed) = self.records.front().map(|record| record.elapsed) {
+            clock.advance_to(elapsed);
+            self.step(receiver).unwrap()?;
+            replayed += 1;
+        }
+        Ok(replayed)
+    }
+}
+
+impl<E> std::fmt::Debug for Replayer<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{remaining: {}}}", self.records.len())
+    }
+}
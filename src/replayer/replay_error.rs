@@ -0,0 +1,21 @@
+use crate::recorder::Outcome;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayError {
+    pub sequence: u64,
+    pub expected: Outcome,
+    pub actual: Outcome,
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "event {} was expected to {:?} but did {:?}",
+            self.sequence, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for ReplayError {}
@@ -0,0 +1,303 @@
+pub mod reply_error;
+
+use std::{
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    channel::mailbox::{mailbox, MailboxSender},
+    receive::{Receive, ReceiverResult},
+};
+
+use self::reply_error::ReplyError;
+
+enum State<T> {
+    Pending,
+    Ready(T),
+    Taken,
+    Dropped,
+}
+
+struct Slot<T> {
+    state: State<T>,
+    waker: Option<Waker>,
+    cancelled: bool,
+}
+
+struct Shared<T> {
+    slot: Mutex<Slot<T>>,
+    ready: Condvar,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, Slot<T>> {
+        self.slot.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn resolve(&self, state: State<T>) {
+        let mut slot = self.lock();
+        if let State::Pending = slot.state {
+            slot.state = state;
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+            self.ready.notify_all();
+        }
+    }
+}
+
+fn take<T>(slot: &mut Slot<T>) -> Result<T, ReplyError> {
+    match std::mem::replace(&mut slot.state, State::Taken) {
+        State::Ready(value) => Ok(value),
+        State::Pending => {
+            slot.state = State::Pending;
+            Err(ReplyError::Pending)
+        }
+        State::Taken => Err(ReplyError::Taken),
+        State::Dropped => {
+            slot.state = State::Dropped;
+            Err(ReplyError::Dropped)
+        }
+    }
+}
+
+/// creates a oneshot reply, the [`Responder`] sends a single value to the [`Reply`]
+pub fn reply<T>() -> (Responder<T>, Reply<T>) {
+    let shared = Arc::new(Shared {
+        slot: Mutex::new(Slot {
+            state: State::Pending,
+            waker: None,
+            cancelled: false,
+        }),
+        ready: Condvar::new(),
+    });
+    (
+        Responder {
+            shared: shared.clone(),
+        },
+        Reply {
+            shared,
+            detached: false,
+        },
+    )
+}
+
+pub struct Responder<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Responder<T> {
+    /// gives the value back if the reply was cancelled
+    pub fn send(self, value: T) -> Result<(), T> {
+        if self.is_cancelled() {
+            return Err(value);
+        }
+        self.shared.resolve(State::Ready(value));
+        Ok(())
+    }
+
+    /// the [`Reply`] was dropped without being detached, nobody is waiting for the value
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.lock().cancelled
+    }
+}
+
+impl<T> Drop for Responder<T> {
+    fn drop(&mut self) {
+        self.shared.resolve(State::Dropped);
+    }
+}
+
+impl<T> std::fmt::Debug for Responder<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_cancelled() {
+            true => write!(f, "Responder(cancelled)"),
+            false => write!(f, "Responder"),
+        }
+    }
+}
+
+pub struct Reply<T> {
+    shared: Arc<Shared<T>>,
+    detached: bool,
+}
+
+impl<T> Reply<T> {
+    pub fn is_ready(&self) -> bool {
+        !matches!(self.shared.lock().state, State::Pending)
+    }
+
+    pub fn try_get(&mut self) -> Result<T, ReplyError> {
+        take(&mut self.shared.lock())
+    }
+
+    pub fn wait(self) -> Result<T, ReplyError> {
+        let shared = self.shared.clone();
+        let mut slot = shared.lock();
+        while let State::Pending = slot.state {
+            slot = shared
+                .ready
+                .wait(slot)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        take(&mut slot)
+    }
+
+    /// [`Pending`][`ReplyError::Pending`] if nothing was sent in time, the reply can be waited on again
+    pub fn wait_timeout(&mut self, timeout: Duration) -> Result<T, ReplyError> {
+        let deadline = Instant::now() + timeout;
+        let mut slot = self.shared.lock();
+        while let State::Pending = slot.state {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(ReplyError::Pending);
+            }
+            slot = self
+                .shared
+                .ready
+                .wait_timeout(slot, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+        take(&mut slot)
+    }
+
+    /// drops the reply without cancelling the work that answers it
+    pub fn detach(mut self) {
+        self.detached = true;
+    }
+}
+
+impl<T> Future for Reply<T> {
+    type Output = Result<T, ReplyError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.shared.lock();
+        match take(&mut slot) {
+            Err(ReplyError::Pending) => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            result => Poll::Ready(result),
+        }
+    }
+}
+
+impl<T> Drop for Reply<T> {
+    fn drop(&mut self) {
+        if !self.detached {
+            self.shared.lock().cancelled = true;
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Reply<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_ready() {
+            true => write!(f, "Reply(ready)"),
+            false => write!(f, "Reply(pending)"),
+        }
+    }
+}
+
+type Job<R> = Box<dyn FnOnce(&mut R) + Send>;
+
+/// runs the receiver on a worker thread, one event at a time in the order they were sent, replying with its result
+pub struct Deferred<R> {
+    receiver: Arc<Mutex<R>>,
+    jobs: MailboxSender<Job<R>>,
+    // set by the worker once the receiver returned `Delete`
+    deleted: Arc<AtomicBool>,
+}
+
+impl<R: Send + 'static> Deferred<R> {
+    pub fn new(receiver: R) -> Self {
+        let receiver = Arc::new(Mutex::new(receiver));
+        let (jobs, queued) = mailbox::<Job<R>>(None);
+
+        // stops once every clone is dropped and the events already sent are handled
+        let worker = receiver.clone();
+        thread::spawn(move || {
+            while let Some(job) = queued.recv() {
+                let mut receiver = worker.lock().unwrap_or_else(PoisonError::into_inner);
+                // a panicking receiver only drops the reply of its own event
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| job(&mut receiver)));
+            }
+        });
+
+        Self {
+            receiver,
+            jobs,
+            deleted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl<R> Deferred<R> {
+    pub fn get_receiver(&self) -> &Arc<Mutex<R>> {
+        &self.receiver
+    }
+}
+
+impl<E: Send + 'static, R: Receive<E> + Send + 'static> Receive<E> for Deferred<R>
+where
+    R::Output: Send,
+{
+    type Output = Reply<ReceiverResult<E, R::Output>>;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        if !self.is_alive() {
+            return ReceiverResult::Delete(event);
+        }
+
+        let (responder, reply) = reply();
+        let deleted = self.deleted.clone();
+        let job: Job<R> = Box::new(move |receiver| {
+            if responder.is_cancelled() {
+                return;
+            }
+            // events queued behind the one that deleted the receiver are handed back without reaching it
+            let result = match deleted.load(Ordering::Acquire) {
+                true => ReceiverResult::Delete(event),
+                false => receiver.send(event),
+            };
+            if let ReceiverResult::Delete(_) = result {
+                deleted.store(true, Ordering::Release);
+            }
+            let _ = responder.send(result);
+        });
+        // the worker only stops once every sender is dropped, so the job is always queued
+        let _ = self.jobs.send(job);
+        ReceiverResult::Continue(reply)
+    }
+
+    /// turns false once the worker handled an event the receiver returned `Delete` for
+    fn is_alive(&self) -> bool {
+        !self.deleted.load(Ordering::Acquire)
+    }
+}
+
+impl<R> Clone for Deferred<R> {
+    fn clone(&self) -> Self {
+        Self {
+            receiver: self.receiver.clone(),
+            jobs: self.jobs.clone(),
+            deleted: self.deleted.clone(),
+        }
+    }
+}
+
+impl<R: std::fmt::Debug> std::fmt::Debug for Deferred<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Deferred({:?})", self.receiver)
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplyError {
+    /// the value hasn't been sent yet
+    Pending,
+    /// the [`Responder`][`super::Responder`] was dropped without sending a value
+    Dropped,
+    /// the value was already taken by an earlier [`try_get`][`super::Reply::try_get`] or poll
+    Taken,
+}
+
+impl std::fmt::Display for ReplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplyError::Pending => write!(f, "reply is still pending"),
+            ReplyError::Dropped => write!(f, "responder was dropped without replying"),
+            ReplyError::Taken => write!(f, "reply was already taken"),
+        }
+    }
+}
+
+impl std::error::Error for ReplyError {}
@@ -1,17 +1,20 @@
+pub mod route_policy;
+
 use counted_map::ReassignableCountedMap;
 
 use crate::{
-    exposed::Exposed,
+    exposed::ExposedBase,
     receive::{Receive, ReceiverResult},
-    view::View,
 };
 
-pub trait Route<E>: Receive<E> {
-    fn intercept(&mut self, intercept: Box<dyn Route<E, Output = E>>);
+use self::route_policy::{InterceptPolicy, LocalRoutes, RoutePolicy};
+
+pub trait Route<'a, E, P: RoutePolicy<'a, E> = LocalRoutes>: Receive<E> {
+    fn intercept(&mut self, intercept: Box<P::Intercept>);
 
-    fn take_intercept(&mut self) -> Option<Box<dyn Route<E, Output = E>>>;
+    fn take_intercept(&mut self) -> Option<Box<P::Intercept>>;
 
-    fn intercept_at_root(&mut self, intercept: Box<dyn Route<E, Output = E>>) {
+    fn intercept_at_root(&mut self, intercept: Box<P::Intercept>) {
         let old_intercept = self.take_intercept();
         match old_intercept {
             Some(r) => {
@@ -23,12 +26,16 @@ pub trait Route<E>: Receive<E> {
     }
 }
 
-pub struct Router<E, R: Receive<E>> {
-    intercept: Option<Box<dyn Route<E, Output = E>>>,
+pub struct RouterBase<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> {
+    intercept: Option<Box<P::Intercept>>,
     receiver: R,
 }
 
-impl<E, R: Receive<E>> Router<E, R> {
+/// a router without any bounds on its intercepts, see [`SendRouter`][`crate::send_router::SendRouter`] and
+/// [`SyncRouter`][`crate::send_router::SyncRouter`] for routers that can cross threads
+pub type Router<'a, E, R> = RouterBase<'a, E, R, LocalRoutes>;
+
+impl<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> RouterBase<'a, E, R, P> {
     pub fn new(receiver: R) -> Self {
         Self {
             intercept: None,
@@ -36,58 +43,61 @@ impl<E, R: Receive<E>> Router<E, R> {
         }
     }
 
-    pub fn with_intercept(intercept: Box<dyn Route<E, Output = E>>, receiver: R) -> Self {
+    pub fn with_intercept(intercept: Box<P::Intercept>, receiver: R) -> Self {
         Self {
             intercept: Some(intercept),
             receiver,
         }
     }
 
-    pub fn with_intercept_from_receiver(
-        intercept: impl Receive<E, Output = E> + 'static,
+    pub fn with_intercept_from_receiver<T: Receive<E, Output = E>>(
+        intercept: T,
         receiver: R,
     ) -> Self
     where
-        E: 'static,
+        P: InterceptPolicy<'a, E, RouterBase<'a, E, T, P>>,
     {
-        Self::with_intercept(Box::new(Router::new(intercept)), receiver)
+        Self::with_intercept(P::boxed_intercept(RouterBase::new(intercept)), receiver)
     }
 
-    pub fn new_exposed(receiver: R) -> Router<E, Exposed<E, R>> {
-        Router {
+    pub fn new_exposed(receiver: R) -> RouterBase<'a, E, ExposedBase<'a, E, R, P>, P> {
+        RouterBase {
             intercept: None,
-            receiver: Exposed::new(receiver),
+            receiver: ExposedBase::new(receiver),
         }
     }
 
     pub fn new_exposed_with_viewers(
         receiver: R,
-        viewers: ReassignableCountedMap<usize, Box<dyn View<E>>>,
-    ) -> Router<E, Exposed<E, R>> {
-        Router {
+        viewers: ReassignableCountedMap<usize, Box<P::Viewer>>,
+    ) -> RouterBase<'a, E, ExposedBase<'a, E, R, P>, P> {
+        RouterBase {
             intercept: None,
-            receiver: Exposed::with_viewers(viewers, receiver),
+            receiver: ExposedBase::with_viewers(viewers, receiver),
         }
     }
 
     pub fn new_exposed_with_intercept(
-        intercept: Box<dyn Route<E, Output = E>>,
+        intercept: Box<P::Intercept>,
         receiver: R,
-    ) -> Router<E, Exposed<E, R>> {
-        Router {
+    ) -> RouterBase<'a, E, ExposedBase<'a, E, R, P>, P> {
+        RouterBase {
             intercept: Some(intercept),
-            receiver: Exposed::new(receiver),
+            receiver: ExposedBase::new(receiver),
         }
     }
 
-    pub fn new_exposed_with_intercept_from_receiver(
-        intercept: impl Receive<E, Output = E> + 'static,
+    pub fn new_exposed_with_intercept_from_receiver<T: Receive<E, Output = E>>(
+        intercept: T,
         receiver: R,
-    ) -> Router<E, Exposed<E, R>>
+    ) -> RouterBase<'a, E, ExposedBase<'a, E, R, P>, P>
     where
-        E: 'static,
+        P: InterceptPolicy<'a, E, RouterBase<'a, E, T, P>>,
     {
-        Router::new_exposed_with_intercept(Box::new(Router::new(intercept)), receiver)
+        RouterBase::new_exposed_with_intercept(
+            P::boxed_intercept(RouterBase::new(intercept)),
+            receiver,
+        )
     }
 
     pub fn get_receiver(&self) -> &R {
@@ -98,38 +108,53 @@ impl<E, R: Receive<E>> Router<E, R> {
         &mut self.receiver
     }
 
-    pub fn get_intercept(&self) -> Option<&dyn Route<E, Output = E>> {
-        self.intercept.as_ref().map(Box::as_ref)
+    pub fn get_intercept(&self) -> Option<&P::Intercept> {
+        self.intercept.as_deref()
     }
 
-    pub fn intercept_from_receiver(&mut self, intercept: impl Receive<E, Output = E> + 'static)
+    pub fn intercept_from_receiver<T: Receive<E, Output = E>>(&mut self, intercept: T)
     where
-        E: 'static,
+        P: InterceptPolicy<'a, E, RouterBase<'a, E, T, P>>,
     {
-        let intercept = Box::new(Router::new(intercept));
+        let intercept = P::boxed_intercept(RouterBase::new(intercept));
         self.intercept(intercept);
     }
 
-    pub fn intercept_at_root_from_receiver(
-        &mut self,
-        intercept: impl Receive<E, Output = E> + 'static,
-    ) where
-        E: 'static,
+    pub fn intercept_at_root_from_receiver<T: Receive<E, Output = E>>(&mut self, intercept: T)
+    where
+        P: InterceptPolicy<'a, E, RouterBase<'a, E, T, P>>,
     {
-        let intercept = Box::new(Router::new(intercept));
+        let intercept = P::boxed_intercept(RouterBase::new(intercept));
         self.intercept_at_root(intercept);
     }
 
-    pub fn delete_top_intercept(&mut self) -> Option<Box<dyn Route<E, Output = E>>> {
+    pub fn delete_top_intercept(&mut self) -> Option<Box<P::Intercept>> {
         let mut old_intercept = self.take_intercept();
         if let Some(ref mut intercept) = old_intercept {
             self.intercept = intercept.take_intercept();
         }
         old_intercept
     }
+
+    pub fn collect_garbage(&mut self) -> usize {
+        let mut removed = 0;
+        while self
+            .intercept
+            .as_ref()
+            .is_some_and(|intercept| !intercept.is_alive())
+        {
+            self.delete_top_intercept();
+            removed += 1;
+        }
+
+        if let Some(ref mut intercept) = self.intercept {
+            removed += intercept.collect_garbage();
+        }
+        removed + self.receiver.collect_garbage()
+    }
 }
 
-impl<E, R: Receive<E>> Receive<E> for Router<E, R> {
+impl<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> Receive<E> for RouterBase<'a, E, R, P> {
     type Output = R::Output;
     fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
         let event = if let Some(ref mut intercept) = self.intercept {
@@ -147,28 +172,38 @@ impl<E, R: Receive<E>> Receive<E> for Router<E, R> {
 
         self.receiver.send(event)
     }
+
+    fn is_alive(&self) -> bool {
+        self.receiver.is_alive()
+    }
+
+    fn collect_garbage(&mut self) -> usize {
+        RouterBase::collect_garbage(self)
+    }
 }
 
-impl<E, R: Receive<E>> Route<E> for Router<E, R> {
-    fn intercept(&mut self, intercept: Box<dyn Route<E, Output = E>>) {
+impl<'a, E, R: Receive<E>, P: RoutePolicy<'a, E>> Route<'a, E, P> for RouterBase<'a, E, R, P> {
+    fn intercept(&mut self, intercept: Box<P::Intercept>) {
         match self.intercept {
             Some(ref mut child) => child.intercept(intercept),
             None => self.intercept = Some(intercept),
         }
     }
 
-    fn take_intercept(&mut self) -> Option<Box<dyn Route<E, Output = E>>> {
+    fn take_intercept(&mut self) -> Option<Box<P::Intercept>> {
         self.intercept.take()
     }
 }
 
-impl<E, R: Receive<E> + Default> Default for Router<E, R> {
+impl<'a, E, R: Receive<E> + Default, P: RoutePolicy<'a, E>> Default for RouterBase<'a, E, R, P> {
     fn default() -> Self {
         Self::new(R::default())
     }
 }
 
-impl<E, R: Receive<E> + std::fmt::Debug> std::fmt::Debug for Router<E, R> {
+impl<'a, E, R: Receive<E> + std::fmt::Debug, P: RoutePolicy<'a, E>> std::fmt::Debug
+    for RouterBase<'a, E, R, P>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{")?;
         match self.intercept {
@@ -179,7 +214,9 @@ impl<E, R: Receive<E> + std::fmt::Debug> std::fmt::Debug for Router<E, R> {
     }
 }
 
-impl<E, R: Receive<E> + std::fmt::Display> std::fmt::Display for Router<E, R> {
+impl<'a, E, R: Receive<E> + std::fmt::Display, P: RoutePolicy<'a, E>> std::fmt::Display
+    for RouterBase<'a, E, R, P>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.receiver.fmt(f)
     }
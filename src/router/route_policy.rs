@@ -0,0 +1,113 @@
+use crate::{receive::Receive, view::View};
+
+use super::{Route, RouterBase};
+
+/// chooses how a [`RouterBase`][`super::RouterBase`] and [`ExposedBase`][`crate::exposed::ExposedBase`] box their
+/// intercepts and viewers, which decides whether the containers are `Send` or `Sync`
+pub trait RoutePolicy<'a, E>: Sized {
+    type Intercept: ?Sized + Route<'a, E, Self, Output = E>;
+    type Viewer: ?Sized + View<E>;
+}
+
+pub trait InterceptPolicy<'a, E, T>: RoutePolicy<'a, E> {
+    fn boxed_intercept(intercept: T) -> Box<Self::Intercept>;
+}
+
+pub trait ViewerPolicy<'a, E, V>: RoutePolicy<'a, E> {
+    fn boxed_viewer(viewer: V) -> Box<Self::Viewer>;
+}
+
+// no bounds
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LocalRoutes;
+
+impl<'a, E> RoutePolicy<'a, E> for LocalRoutes {
+    type Intercept = dyn Route<'a, E, LocalRoutes, Output = E> + 'a;
+    type Viewer = dyn View<E> + 'a;
+}
+
+impl<'a, E, T: Route<'a, E, LocalRoutes, Output = E> + 'a> InterceptPolicy<'a, E, T>
+    for LocalRoutes
+{
+    fn boxed_intercept(intercept: T) -> Box<Self::Intercept> {
+        Box::new(intercept)
+    }
+}
+
+impl<'a, E, V: View<E> + 'a> ViewerPolicy<'a, E, V> for LocalRoutes {
+    fn boxed_viewer(viewer: V) -> Box<Self::Viewer> {
+        Box::new(viewer)
+    }
+}
+
+// Send
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SendRoutes;
+
+impl<'a, E> RoutePolicy<'a, E> for SendRoutes {
+    type Intercept = dyn Route<'a, E, SendRoutes, Output = E> + Send + 'a;
+    type Viewer = dyn View<E> + Send + 'a;
+}
+
+impl<'a, E, T: Route<'a, E, SendRoutes, Output = E> + Send + 'a> InterceptPolicy<'a, E, T>
+    for SendRoutes
+{
+    fn boxed_intercept(intercept: T) -> Box<Self::Intercept> {
+        Box::new(intercept)
+    }
+}
+
+impl<'a, E, V: View<E> + Send + 'a> ViewerPolicy<'a, E, V> for SendRoutes {
+    fn boxed_viewer(viewer: V) -> Box<Self::Viewer> {
+        Box::new(viewer)
+    }
+}
+
+// Send + Sync
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SyncRoutes;
+
+impl<'a, E> RoutePolicy<'a, E> for SyncRoutes {
+    type Intercept = dyn Route<'a, E, SyncRoutes, Output = E> + Send + Sync + 'a;
+    type Viewer = dyn View<E> + Send + Sync + 'a;
+}
+
+impl<'a, E, T: Route<'a, E, SyncRoutes, Output = E> + Send + Sync + 'a> InterceptPolicy<'a, E, T>
+    for SyncRoutes
+{
+    fn boxed_intercept(intercept: T) -> Box<Self::Intercept> {
+        Box::new(intercept)
+    }
+}
+
+impl<'a, E, V: View<E> + Send + Sync + 'a> ViewerPolicy<'a, E, V> for SyncRoutes {
+    fn boxed_viewer(viewer: V) -> Box<Self::Viewer> {
+        Box::new(viewer)
+    }
+}
+
+/// a viewer that `P` can box, lets a method that is generic over the event type take `impl IntoViewer<..>` so
+/// callers only have to name the event type
+pub trait IntoViewer<'a, E, P: RoutePolicy<'a, E>> {
+    fn into_viewer(self) -> Box<P::Viewer>;
+}
+
+impl<'a, E, V, P: ViewerPolicy<'a, E, V>> IntoViewer<'a, E, P> for V {
+    fn into_viewer(self) -> Box<P::Viewer> {
+        P::boxed_viewer(self)
+    }
+}
+
+/// a receiver that `P` can box as an intercept once it is wrapped in a [`RouterBase`][`super::RouterBase`], the
+/// [`IntoViewer`] of intercepts
+pub trait IntoIntercept<'a, E, P: RoutePolicy<'a, E>> {
+    fn into_intercept(self) -> Box<P::Intercept>;
+}
+
+impl<'a, E, T: Receive<E, Output = E>, P: InterceptPolicy<'a, E, RouterBase<'a, E, T, P>>>
+    IntoIntercept<'a, E, P> for T
+{
+    fn into_intercept(self) -> Box<P::Intercept> {
+        P::boxed_intercept(RouterBase::new(self))
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{
+    event_bus::{BusRouterBase, EventBusBase},
+    router::route_policy::{SendRoutes, SyncRoutes},
+};
+
+pub type SendBusRouter<E> = BusRouterBase<E, SendRoutes>;
+
+pub type SyncBusRouter<E> = BusRouterBase<E, SyncRoutes>;
+
+pub type SendEventBus = EventBusBase<SendRoutes>;
+
+pub type SyncEventBus = EventBusBase<SyncRoutes>;
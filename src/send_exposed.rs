@@ -0,0 +1,8 @@
+use crate::{
+    exposed::ExposedBase,
+    router::route_policy::{SendRoutes, SyncRoutes},
+};
+
+pub type SendExposed<'a, E, R> = ExposedBase<'a, E, R, SendRoutes>;
+
+pub type SyncExposed<'a, E, R> = ExposedBase<'a, E, R, SyncRoutes>;
@@ -0,0 +1,12 @@
+use crate::router::{
+    route_policy::{SendRoutes, SyncRoutes},
+    Route, RouterBase,
+};
+
+pub type SendIntercept<'a, E> = dyn Route<'a, E, SendRoutes, Output = E> + Send + 'a;
+
+pub type SyncIntercept<'a, E> = dyn Route<'a, E, SyncRoutes, Output = E> + Send + Sync + 'a;
+
+pub type SendRouter<'a, E, R> = RouterBase<'a, E, R, SendRoutes>;
+
+pub type SyncRouter<'a, E, R> = RouterBase<'a, E, R, SyncRoutes>;
@@ -0,0 +1,151 @@
+use crate::{
+    multi_router::MultiRoute,
+    receive::{pass_receiver::PassReceiver, Receive, ReceiverResult},
+    router::{Route, Router},
+};
+
+pub type EventStack<E> = Router<'static, E, PassReceiver>;
+
+pub trait HasStack<E> {
+    fn stack(&self) -> &EventStack<E>;
+
+    fn stack_mut(&mut self) -> &mut EventStack<E>;
+}
+
+impl<E> HasStack<E> for EventStack<E> {
+    fn stack(&self) -> &EventStack<E> {
+        self
+    }
+
+    fn stack_mut(&mut self) -> &mut EventStack<E> {
+        self
+    }
+}
+
+pub struct StackRouter<S, R> {
+    stacks: S,
+    receiver: R,
+}
+
+impl<S, R> StackRouter<S, R> {
+    pub fn new(receiver: R) -> Self
+    where
+        S: Default,
+    {
+        Self::with_stacks(S::default(), receiver)
+    }
+
+    pub fn with_stacks(stacks: S, receiver: R) -> Self {
+        Self { stacks, receiver }
+    }
+
+    pub fn get_receiver(&self) -> &R {
+        &self.receiver
+    }
+
+    pub fn get_receiver_mut(&mut self) -> &mut R {
+        &mut self.receiver
+    }
+
+    pub fn get_stacks(&self) -> &S {
+        &self.stacks
+    }
+
+    pub fn get_stacks_mut(&mut self) -> &mut S {
+        &mut self.stacks
+    }
+
+    pub fn get_intercept<E>(&self) -> Option<&dyn Route<'static, E, Output = E>>
+    where
+        S: HasStack<E>,
+    {
+        self.stacks.stack().get_intercept()
+    }
+
+    pub fn intercept<E>(&mut self, intercept: Box<dyn Route<'static, E, Output = E>>)
+    where
+        S: HasStack<E>,
+    {
+        self.stacks.stack_mut().intercept(intercept)
+    }
+
+    pub fn intercept_from_receiver<E: 'static>(
+        &mut self,
+        intercept: impl Receive<E, Output = E> + 'static,
+    ) where
+        S: HasStack<E>,
+    {
+        self.stacks.stack_mut().intercept_from_receiver(intercept)
+    }
+
+    pub fn delete_top_intercept<E>(&mut self) -> Option<Box<dyn Route<'static, E, Output = E>>>
+    where
+        S: HasStack<E>,
+    {
+        self.stacks.stack_mut().delete_top_intercept()
+    }
+
+    pub fn collect_garbage<E>(&mut self) -> usize
+    where
+        S: HasStack<E>,
+    {
+        self.stacks.stack_mut().collect_garbage()
+    }
+}
+
+impl<E, S: HasStack<E>, R: Receive<E>> Receive<E> for StackRouter<S, R> {
+    type Output = R::Output;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        match self.stacks.stack_mut().send(event) {
+            ReceiverResult::Continue(event) | ReceiverResult::Delete(event) => {
+                self.receiver.send(event)
+            }
+            ReceiverResult::Stop => ReceiverResult::Stop,
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.receiver.is_alive()
+    }
+}
+
+impl<E, S: HasStack<E>, R> MultiRoute<dyn Route<'static, E, Output = E>> for StackRouter<S, R> {
+    fn take_intercept(&mut self) -> Option<Box<dyn Route<'static, E, Output = E>>> {
+        self.stacks.stack_mut().take_intercept()
+    }
+
+    fn delete_top_intercept(&mut self) -> Option<Box<dyn Route<'static, E, Output = E>>> {
+        StackRouter::delete_top_intercept(self)
+    }
+
+    fn intercept(&mut self, intercept: Box<dyn Route<'static, E, Output = E>>) {
+        StackRouter::intercept(self, intercept)
+    }
+
+    fn collect_garbage(&mut self) -> usize {
+        StackRouter::collect_garbage::<E>(self)
+    }
+}
+
+impl<S: Default, R: Default> Default for StackRouter<S, R> {
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
+
+impl<S: std::fmt::Debug, R: std::fmt::Debug> std::fmt::Debug for StackRouter<S, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{stacks: {:?}, receiver: {:?}}}",
+            self.stacks, self.receiver
+        )
+    }
+}
+
+impl<S, R: std::fmt::Display> std::fmt::Display for StackRouter<S, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.receiver.fmt(f)
+    }
+}
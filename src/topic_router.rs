@@ -0,0 +1,216 @@
+pub mod topic_error;
+
+use std::collections::HashMap;
+
+use crate::{
+    event_bus::{send_exposed, Published},
+    exposed::Exposed,
+    receive::{pass_receiver::PassReceiver, Receive, ReceiverResult},
+    router::{Route, Router},
+    view::View,
+};
+
+use self::topic_error::TopicError;
+
+pub type TopicRoute<'a, E> = Router<'a, E, Exposed<'a, E, PassReceiver>>;
+
+struct TopicNode<'a, E> {
+    children: HashMap<String, TopicNode<'a, E>>,
+    any: Option<Box<TopicNode<'a, E>>>,
+    route: Option<TopicRoute<'a, E>>,
+    rest: Option<TopicRoute<'a, E>>,
+}
+
+impl<'a, E> TopicNode<'a, E> {
+    fn new() -> Self {
+        Self {
+            children: HashMap::new(),
+            any: None,
+            route: None,
+            rest: None,
+        }
+    }
+
+    fn route(&self, segments: &[&str]) -> Option<&TopicRoute<'a, E>> {
+        match segments {
+            [] => self.route.as_ref(),
+            ["#"] => self.rest.as_ref(),
+            ["*", rest @ ..] => self.any.as_ref()?.route(rest),
+            [first, rest @ ..] => self.children.get(*first)?.route(rest),
+        }
+    }
+
+    fn route_mut(&mut self, segments: &[&str]) -> Option<&mut TopicRoute<'a, E>> {
+        match segments {
+            [] => self.route.as_mut(),
+            ["#"] => self.rest.as_mut(),
+            ["*", rest @ ..] => self.any.as_mut()?.route_mut(rest),
+            [first, rest @ ..] => self.children.get_mut(*first)?.route_mut(rest),
+        }
+    }
+
+    fn route_or_insert(&mut self, segments: &[&str]) -> &mut TopicRoute<'a, E> {
+        match segments {
+            [] => self
+                .route
+                .get_or_insert_with(|| Router::new_exposed(PassReceiver)),
+            ["#"] => self
+                .rest
+                .get_or_insert_with(|| Router::new_exposed(PassReceiver)),
+            ["*", rest @ ..] => self
+                .any
+                .get_or_insert_with(|| Box::new(TopicNode::new()))
+                .route_or_insert(rest),
+            [first, rest @ ..] => self
+                .children
+                .entry(first.to_string())
+                .or_insert_with(TopicNode::new)
+                .route_or_insert(rest),
+        }
+    }
+
+    // literal segments are matched before `*`, which is matched before `#`, returns `None` once stopped
+    fn publish(&mut self, segments: &[&str], event: E, handled: &mut bool) -> Option<E> {
+        let mut event = event;
+        match segments.split_first() {
+            None => {
+                if let Some(ref mut route) = self.route {
+                    event = deliver(route, event, handled)?;
+                }
+            }
+            Some((first, rest)) => {
+                if let Some(child) = self.children.get_mut(*first) {
+                    event = child.publish(rest, event, handled)?;
+                }
+                if let Some(ref mut any) = self.any {
+                    event = any.publish(rest, event, handled)?;
+                }
+            }
+        }
+
+        match self.rest {
+            Some(ref mut rest) => deliver(rest, event, handled),
+            None => Some(event),
+        }
+    }
+
+    fn collect_garbage(&mut self) -> usize {
+        let routes = self
+            .route
+            .iter_mut()
+            .chain(self.rest.iter_mut())
+            .map(|route| route.collect_garbage())
+            .sum::<usize>();
+        let children = self
+            .children
+            .values_mut()
+            .chain(self.any.as_deref_mut())
+            .map(TopicNode::collect_garbage)
+            .sum::<usize>();
+        routes + children
+    }
+}
+
+fn deliver<E>(route: &mut TopicRoute<'_, E>, event: E, handled: &mut bool) -> Option<E> {
+    let (result, delivered) = send_exposed(route, event);
+    *handled |= delivered;
+    match result {
+        ReceiverResult::Continue(event) | ReceiverResult::Delete(event) => Some(event),
+        ReceiverResult::Stop => None,
+    }
+}
+
+fn segments(pattern: &str) -> Result<Vec<&str>, TopicError> {
+    let segments: Vec<&str> = pattern.split('.').collect();
+    match segments.iter().position(|segment| *segment == "#") {
+        Some(position) if position != segments.len() - 1 => Err(TopicError::MisplacedWildcard),
+        _ => Ok(segments),
+    }
+}
+
+pub struct TopicRouter<'a, E> {
+    root: TopicNode<'a, E>,
+}
+
+impl<'a, E> TopicRouter<'a, E> {
+    pub fn new() -> Self {
+        Self {
+            root: TopicNode::new(),
+        }
+    }
+
+    pub fn route(&self, pattern: &str) -> Option<&TopicRoute<'a, E>> {
+        self.root.route(&segments(pattern).ok()?)
+    }
+
+    pub fn route_mut(&mut self, pattern: &str) -> Option<&mut TopicRoute<'a, E>> {
+        self.root.route_mut(&segments(pattern).ok()?)
+    }
+
+    pub fn subscribe(
+        &mut self,
+        pattern: &str,
+        viewer: impl View<E> + 'a,
+    ) -> Result<usize, TopicError> {
+        let segments = segments(pattern)?;
+        Ok(self
+            .root
+            .route_or_insert(&segments)
+            .get_receiver_mut()
+            .box_and_add_viewer(viewer)?)
+    }
+
+    pub fn unsubscribe(&mut self, pattern: &str, id: usize) -> Option<Box<dyn View<E> + 'a>> {
+        self.route_mut(pattern)?
+            .get_receiver_mut()
+            .remove_viewer(id)
+    }
+
+    pub fn intercept(
+        &mut self,
+        pattern: &str,
+        intercept: Box<dyn Route<'a, E, Output = E> + 'a>,
+    ) -> Result<(), TopicError> {
+        let segments = segments(pattern)?;
+        self.root.route_or_insert(&segments).intercept(intercept);
+        Ok(())
+    }
+
+    pub fn intercept_from_receiver(
+        &mut self,
+        pattern: &str,
+        intercept: impl Receive<E, Output = E> + 'a,
+    ) -> Result<(), TopicError>
+    where
+        E: 'a,
+    {
+        self.intercept(pattern, Box::new(Router::new(intercept)))
+    }
+
+    pub fn delete_top_intercept(
+        &mut self,
+        pattern: &str,
+    ) -> Option<Box<dyn Route<'a, E, Output = E> + 'a>> {
+        self.route_mut(pattern)?.delete_top_intercept()
+    }
+
+    pub fn publish(&mut self, topic: &str, event: E) -> Published<E> {
+        let segments: Vec<&str> = topic.split('.').collect();
+        let mut handled = false;
+        match self.root.publish(&segments, event, &mut handled) {
+            Some(event) if handled => Published::Handled(event),
+            Some(event) => Published::Unhandled(event),
+            None => Published::Stopped,
+        }
+    }
+
+    pub fn collect_garbage(&mut self) -> usize {
+        self.root.collect_garbage()
+    }
+}
+
+impl<E> Default for TopicRouter<'_, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,25 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TopicError {
+    /// a `#` wildcard was used anywhere but the last segment of the pattern
+    MisplacedWildcard,
+    /// the subscriber map of the pattern is full
+    Full,
+}
+
+impl From<counted_map::HashMapFull> for TopicError {
+    fn from(_: counted_map::HashMapFull) -> Self {
+        TopicError::Full
+    }
+}
+
+impl std::fmt::Display for TopicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopicError::MisplacedWildcard => write!(f, "`#` must be the last segment of a pattern"),
+            TopicError::Full => write!(f, "too many subscribers to the pattern"),
+        }
+    }
+}
+
+impl std::error::Error for TopicError {}
@@ -1,14 +1,23 @@
 use std::{
     cell::RefCell,
     rc::Rc,
-    sync::{Arc, Mutex, RwLock},
+    sync::{Arc, Mutex, PoisonError, RwLock},
 };
 
 pub trait View<E> {
     fn view(&mut self, event: &E) -> Option<DeleteView>;
+
+    fn is_alive(&self) -> bool {
+        true
+    }
+}
+
+pub trait SharedView<E> {
+    fn view(&self, event: &E) -> Option<DeleteView>;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteView;
 
 // Rc + RefCell
@@ -21,27 +30,35 @@ impl<E, R: View<E>> View<E> for Rc<RefCell<R>> {
 // Rc + Mutex
 impl<E, R: View<E>> View<E> for Rc<Mutex<R>> {
     fn view(&mut self, event: &E) -> Option<DeleteView> {
-        self.lock().unwrap().view(event)
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .view(event)
     }
 }
 
 // Rc + RwLock
 impl<E, R: View<E>> View<E> for Rc<RwLock<R>> {
     fn view(&mut self, event: &E) -> Option<DeleteView> {
-        self.write().unwrap().view(event)
+        self.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .view(event)
     }
 }
 
 // Arc + Mutex
 impl<E, R: View<E>> View<E> for Arc<Mutex<R>> {
     fn view(&mut self, event: &E) -> Option<DeleteView> {
-        self.lock().unwrap().view(event)
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .view(event)
     }
 }
 
 // Arc + RwLock
 impl<E, R: View<E>> View<E> for Arc<RwLock<R>> {
     fn view(&mut self, event: &E) -> Option<DeleteView> {
-        self.write().unwrap().view(event)
+        self.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .view(event)
     }
 }
@@ -0,0 +1,184 @@
+use data_router::{
+    multi_exposed::MultiExpose,
+    multi_router::MultiRoute,
+    receive::{Receive, ReceiverResult},
+    view::{DeleteView, View},
+};
+
+mod routers {
+    #![allow(dead_code, non_camel_case_types)]
+
+    // shadow prelude names to make sure the expansions only use absolute paths
+    struct Option;
+    struct Box;
+    struct Vec;
+    struct Default;
+    struct Result;
+    mod std {}
+    mod event_horizon {}
+
+    data_router::multi_router_intercept_trait!(pub CountIntercept for i32 | bool);
+
+    data_router::multi_router!(
+        pub CountRouter {
+            counts as CountIntercept where i32 => i32 | bool => bool
+        } else {
+            String => String
+        }
+    );
+
+    data_router::impl_multi_router_intercept_trait!(CountRouter as CountIntercept for i32 | bool);
+
+    data_router::multi_exposed::multi_exposed_trait!(pub CountView for i32 | bool);
+
+    data_router::multi_exposed::multi_exposed!(
+        #[derive()]
+        pub CountExposed {
+            views as CountView for i32 => i32 | bool => bool
+        } else {
+            String => String
+        }
+    );
+}
+
+#[derive(Debug, Default)]
+struct Counter(usize);
+
+impl Receive<i32> for Counter {
+    type Output = i32;
+
+    fn send(&mut self, event: i32) -> ReceiverResult<i32, Self::Output> {
+        self.0 += 1;
+        ReceiverResult::Continue(event)
+    }
+}
+
+impl Receive<bool> for Counter {
+    type Output = bool;
+
+    fn send(&mut self, event: bool) -> ReceiverResult<bool, Self::Output> {
+        self.0 += 1;
+        ReceiverResult::Continue(event)
+    }
+}
+
+impl Receive<String> for Counter {
+    type Output = String;
+
+    fn send(&mut self, event: String) -> ReceiverResult<String, Self::Output> {
+        ReceiverResult::Continue(event)
+    }
+}
+
+impl<E> View<E> for Counter {
+    fn view(&mut self, _: &E) -> Option<DeleteView> {
+        self.0 += 1;
+        None
+    }
+}
+
+#[test]
+fn multi_router_outside_crate() {
+    let mut router = routers::CountRouter::new(Counter::default());
+    router
+        .intercept(Box::new(routers::CountRouter::new(Counter::default()))
+            as Box<dyn routers::CountIntercept>);
+
+    assert_eq!(router.send(1).unwrap_continue(), 1);
+    assert!(router.send(true).unwrap_continue());
+    assert_eq!(router.send("pass".to_string()).unwrap_continue(), "pass");
+    assert_eq!(router.get_receiver().0, 2);
+    assert_eq!(router.collect_garbage(), 0);
+    assert!(format!("{:?}", router).starts_with("{[counts: intercepted], "));
+
+    assert!(router.delete_top_intercept().is_some());
+    assert!(router.counts().is_none());
+}
+
+#[test]
+fn multi_exposed_outside_crate() {
+    let mut exposed = routers::CountExposed::new(Counter::default());
+    MultiExpose::<dyn routers::CountView>::add_viewer(&mut exposed, Box::new(Counter::default()))
+        .unwrap();
+
+    assert_eq!(exposed.send(1).unwrap_continue(), 1);
+    assert!(!exposed.send(false).unwrap_continue());
+    assert_eq!(exposed.views().len(), 1);
+    assert_eq!(exposed.get_receiver().0, 2);
+    assert_eq!(exposed.collect_garbage(), 0);
+}
+
+mod exposed_routers {
+    data_router::multi_router_intercept_trait!(pub LogIntercept for i32 | u8);
+    data_router::multi_exposed::multi_exposed_trait!(pub LogView for i32 | u8);
+
+    data_router::multi_exposed_router!(
+        pub LogExposedRouter {
+            log as LogIntercept + log_viewers as LogView where i32 | u8
+        } else {
+            String
+        }
+    );
+
+    data_router::impl_multi_router_intercept_trait!(LogExposedRouter as LogIntercept for i32 | u8);
+}
+
+#[derive(Clone, Default)]
+struct Log(
+    std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>,
+    &'static str,
+);
+
+impl<E> Receive<E> for Log {
+    type Output = E;
+
+    fn send(&mut self, event: E) -> ReceiverResult<E, Self::Output> {
+        self.0.borrow_mut().push(self.1);
+        ReceiverResult::Continue(event)
+    }
+}
+
+impl<E> View<E> for Log {
+    fn view(&mut self, _: &E) -> Option<DeleteView> {
+        self.0.borrow_mut().push(self.1);
+        None
+    }
+}
+
+#[test]
+fn multi_exposed_router_ordering() {
+    use data_router::rc_linker::RcLinker;
+    use exposed_routers::{LogExposedRouter, LogIntercept, LogView};
+
+    let log = Log::default();
+    let mut router = LogExposedRouter::new(Log(log.0.clone(), "receiver"));
+
+    let intercept = RcLinker::new(Log(log.0.clone(), "intercept"));
+    MultiRoute::<dyn LogIntercept>::intercept(
+        &mut router,
+        Box::new(LogExposedRouter::new(intercept.linked())),
+    );
+    MultiExpose::<dyn LogView>::add_viewer(&mut router, Box::new(Log(log.0.clone(), "viewer")))
+        .unwrap();
+
+    router.send(1i32);
+    router.send(2u8);
+    router.send("skips the intercepts and viewers".to_string());
+    assert_eq!(
+        *log.0.borrow(),
+        vec![
+            "intercept",
+            "viewer",
+            "receiver",
+            "intercept",
+            "viewer",
+            "receiver",
+            "receiver"
+        ]
+    );
+    assert_eq!(router.log_viewers().len(), 1);
+
+    drop(intercept);
+    assert_eq!(router.collect_garbage(), 1);
+    assert!(router.log().is_none());
+}